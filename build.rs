@@ -0,0 +1,55 @@
+//! Encode the bundled snapshot from data/public_suffix_list.dat.
+//!
+//! To update the snapshot, replace that file with a newer copy of
+//! https://publicsuffix.org/list/public_suffix_list.dat and rebuild.
+//!
+//! The version of the snapshot is the "// VERSION:" line of the list. A copy
+//! taken from the upstream repository has none, its version then goes in
+//! data/public_suffix_list.version, e.g. "2023-02-09_23-26-00_UTC" for the
+//! revision committed on 2023-02-09 at 23:26 UTC.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/trie/encode.rs"]
+mod encode;
+
+const PUBLIC_SUFFIX_LIST: &str = "data/public_suffix_list.dat";
+const PRIVATE_DOMAINS_MARKER: &str = "// ===BEGIN PRIVATE DOMAINS===";
+const VERSION_MARKER: &str = "// VERSION:";
+const VERSION_FILE: &str = "data/public_suffix_list.version";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", PUBLIC_SUFFIX_LIST);
+    println!("cargo:rerun-if-changed={}", VERSION_FILE);
+    println!("cargo:rerun-if-changed=src/trie/encode.rs");
+
+    let list = fs::read_to_string(PUBLIC_SUFFIX_LIST).expect("cannot read the public suffix list");
+    let (icann, private) = match list.find(PRIVATE_DOMAINS_MARKER) {
+        Some(i) => list.split_at(i),
+        None => (list.as_str(), ""),
+    };
+
+    let mut builder = encode::TrieBuilder::default();
+    for rule in icann.lines().filter_map(encode::parse_rule) {
+        builder.insert(rule, encode::ICANN);
+    }
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+    fs::write(out_dir.join("snapshot_icann.trie"), builder.encode()).unwrap();
+
+    for rule in private.lines().filter_map(encode::parse_rule) {
+        builder.insert(rule, encode::PRIVATE);
+    }
+    fs::write(out_dir.join("snapshot_all.trie"), builder.encode()).unwrap();
+
+    let version_file = fs::read_to_string(VERSION_FILE).unwrap_or_default();
+    let version = icann
+        .lines()
+        .find_map(|line| line.strip_prefix(VERSION_MARKER))
+        .unwrap_or(&version_file)
+        .trim();
+    println!("cargo:rustc-env=TLDEXTRACT_SNAPSHOT_VERSION={}", version);
+}
@@ -1,25 +1,284 @@
+use super::{LoadedRules, Rules, SuffixList, Validators};
 use crate::errors::Result;
-use crate::TldExtractError;
+use crate::trie::{FlatTrie, TrieBytes};
+use crate::{CacheFormat, SuffixKind, TldExtractError, TldOption, TldSource};
+use fs2::FileExt;
 use log::debug;
-use serde_json::{from_reader, to_vec};
-use std::collections::HashSet;
-use std::fs::{read_to_string, File, OpenOptions};
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_slice, to_vec};
+use std::ffi::OsString;
+use std::fs::{self, read_to_string, File, OpenOptions};
 use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-pub fn get_tld_cache<'a, O>(cache_path: O) -> Result<HashSet<String>>
+/// Overrides the directory of the default cache file
+const CACHE_DIR_ENV: &str = "TLDEXTRACT_CACHE";
+
+/// Bumped whenever the layout of the cache file changes
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// Starts a binary cache file, followed by the length of the JSON header as
+/// a little endian `u32`, the header itself and the encoded `FlatTrie`.
+const BINARY_MAGIC: &[u8; 8] = b"TLDXTRIE";
+
+/// Tells which list a cache file was built from, and how
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CacheHeader {
+    format_version: u32,
+    private_domains: bool,
+    source: TldSource,
+    /// The path or url of the source
+    location: Option<String>,
+    list_version: Option<String>,
+    /// When the cache was written, in seconds since the unix epoch
+    written_at: u64,
+    /// CRC32 of the rules, see `CacheFile::checksum`, or of the encoded
+    /// trie in a binary cache
+    checksum: u32,
+    /// The HTTP validators of the remote list
+    #[serde(default)]
+    etag: Option<String>,
+    #[serde(default)]
+    last_modified: Option<String>,
+}
+
+/// When a cache was written, and how to tell whether its remote list changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheStamp {
+    pub written_at: SystemTime,
+    /// The url of the list, if the cache was downloaded
+    pub url: Option<String>,
+    pub validators: Validators,
+}
+
+/// The on-disk cache, rules are kept apart by the section they came from
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    header: CacheHeader,
+    icann: Vec<String>,
+    private: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CacheData {
+    Current(CacheFile),
+    /// Caches written by older versions carry no header, they cannot be trusted
+    Outdated(serde::de::IgnoredAny),
+}
+
+impl CacheFile {
+    fn checksum(icann: &[String], private: &[String]) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+        for (section, rules) in [("icann", icann), ("private", private)].iter() {
+            hasher.update(section.as_bytes());
+            for rule in rules.iter() {
+                hasher.update(b"\n");
+                hasher.update(rule.as_bytes());
+            }
+            hasher.update(b"\n");
+        }
+        hasher.finalize()
+    }
+}
+
+impl CacheHeader {
+    /// Check the cache was built the way the caller would build it now
+    fn validate(
+        &self,
+        checksum: u32,
+        private_domains: bool,
+        local_file: Option<&str>,
+        remote_urls: &[&str],
+    ) -> Result<()> {
+        let mismatch = if self.format_version != CACHE_FORMAT_VERSION {
+            Some(format!("unknown format version {}", self.format_version))
+        } else if self.private_domains != private_domains {
+            Some(format!(
+                "written with private_domains({})",
+                self.private_domains
+            ))
+        } else if local_file.is_some() && self.location.as_deref() != local_file {
+            Some(format!(
+                "written from {} rather than the local public suffix file",
+                self.location.as_deref().unwrap_or("the bundled snapshot")
+            ))
+        } else if local_file.is_none() && self.source == TldSource::LocalFile {
+            Some("written from a local public suffix file".to_string())
+        } else if self.source == TldSource::Remote
+            && !remote_urls.contains(&self.location.as_deref().unwrap_or_default())
+        {
+            Some(format!(
+                "downloaded from {}, which is not a remote url",
+                self.location.as_deref().unwrap_or("an unknown url")
+            ))
+        } else if self.checksum != checksum {
+            Some("checksum mismatch".to_string())
+        } else {
+            None
+        };
+        match mismatch {
+            Some(reason) => Err(TldExtractError::InvalidCacheError(reason)),
+            None => Ok(()),
+        }
+    }
+
+    fn stamp(self) -> CacheStamp {
+        let url = match self.source {
+            TldSource::Remote => self.location,
+            _ => None,
+        };
+        CacheStamp {
+            written_at: UNIX_EPOCH + Duration::from_secs(self.written_at),
+            url,
+            validators: Validators {
+                etag: self.etag,
+                last_modified: self.last_modified,
+            },
+        }
+    }
+}
+
+/// The cache file to use: `TldOption::cache_path` if set, otherwise a file
+/// named after the list, the remote urls and the private domains setting in
+/// the default cache directory. `None` if there is no default cache
+/// directory.
+pub fn cache_path(option: &TldOption) -> Option<PathBuf> {
+    #[cfg(feature = "remote")]
+    let remote_urls = option.remote_urls.as_deref();
+    #[cfg(not(feature = "remote"))]
+    let remote_urls = None;
+    match &option.cache_path {
+        Some(path) => Some(PathBuf::from(path)),
+        None => {
+            let dir = default_cache_dir(|key| std::env::var_os(key))?;
+            Some(dir.join(cache_file_name(
+                option.local_public_suffix_file.as_deref(),
+                remote_urls,
+                option.private_domains,
+            )))
+        }
+    }
+}
+
+/// `$TLDEXTRACT_CACHE`, or `tldextract` in the XDG cache directory, which is
+/// `$XDG_CACHE_HOME` or `~/.cache`.
+///
+/// Without any of them there is none. The temporary directory is no
+/// fallback: other users could write a cache there, or truncate it while
+/// it is mapped.
+fn default_cache_dir<F>(var: F) -> Option<PathBuf>
 where
-    O: Into<Option<&'a str>>,
+    F: Fn(&str) -> Option<OsString>,
 {
+    let var = |key| {
+        var(key)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    if let Some(dir) = var(CACHE_DIR_ENV) {
+        return Some(dir);
+    }
+    let cache_home = var("XDG_CACHE_HOME")
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| var("HOME").map(|home| home.join(".cache")))?;
+    Some(cache_home.join("tldextract"))
+}
+
+/// Caches of different lists, or of the same list with and without the
+/// private domains, get different files. So do caches downloaded from other
+/// than the default remote urls, which would otherwise replace each other's
+/// files and be rejected in turn.
+fn cache_file_name(
+    local_file: Option<&str>,
+    remote_urls: Option<&[String]>,
+    private_domains: bool,
+) -> String {
+    let list = match (local_file, remote_urls) {
+        (Some(path), _) => format!("local-{:08x}", crc32fast::hash(path.as_bytes())),
+        (None, Some(urls)) => format!("remote-{:08x}", crc32fast::hash(urls.join("\n").as_bytes())),
+        (None, None) => "public_suffix_list".to_string(),
+    };
+    let sections = if private_domains { "-private" } else { "" };
+    format!("{}{}.tld_cache", list, sections)
+}
+
+/// Load the cache, provided it was built from the list and with the
+/// private domains setting the caller asks for, along with when and from
+/// where it was written. A downloaded cache must come from one of the
+/// `remote_urls`.
+///
+/// A binary cache is memory mapped and queried in place once its checksum and
+/// layout are checked, a JSON one is parsed.
+pub fn get_tld_cache<P: AsRef<Path>>(
+    cache_path: P,
+    private_domains: bool,
+    local_file: Option<&str>,
+    remote_urls: &[&str],
+) -> Result<(Rules, CacheStamp)> {
     debug!("Trying using local cached TLD data");
-    let cache_path = cache_path.into().unwrap_or(".tld_cache");
     let f = File::open(cache_path)?;
-    Ok(from_reader(f)?)
+    // Safety: the mapped bytes must not change while the map is alive. This
+    // crate never writes to a cache file in place, `write_atomically` renames
+    // a new file over it and the mapped one lives on until it is unmapped.
+    // Nothing keeps another process from truncating or rewriting the file
+    // though, reading the lost pages then kills this process with SIGBUS (an
+    // access violation on Windows), so the cache file is left to this crate.
+    let map = unsafe { Mmap::map(&f)? };
+    if map.starts_with(BINARY_MAGIC) {
+        return get_binary_tld_cache(map, private_domains, local_file, remote_urls);
+    }
+
+    let file = match from_slice(&map)? {
+        CacheData::Current(file) => file,
+        CacheData::Outdated(_) => {
+            return Err(TldExtractError::InvalidCacheError(
+                "no header, written by an older version".to_string(),
+            ))
+        }
+    };
+    let checksum = CacheFile::checksum(&file.icann, &file.private);
+    file.header
+        .validate(checksum, private_domains, local_file, remote_urls)?;
+
+    let icann = file.icann.into_iter().map(|s| (s, SuffixKind::Icann));
+    let private = file.private.into_iter().map(|s| (s, SuffixKind::Private));
+    let list = SuffixList {
+        rules: icann.chain(private).collect(),
+        version: file.header.list_version.clone(),
+    };
+    Ok((list.into(), file.header.stamp()))
 }
 
-pub fn get_tld_from_local_file<O>(
-    local_file_path: O,
-    private_domain: bool,
-) -> Result<HashSet<String>>
+fn get_binary_tld_cache(
+    map: Mmap,
+    private_domains: bool,
+    local_file: Option<&str>,
+    remote_urls: &[&str],
+) -> Result<(Rules, CacheStamp)> {
+    let invalid = || TldExtractError::InvalidCacheError("malformed binary cache".to_string());
+    let header_start = BINARY_MAGIC.len() + 4;
+    let header_len = map
+        .get(BINARY_MAGIC.len()..header_start)
+        .map(|len| u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize)
+        .ok_or_else(invalid)?;
+    let trie_start = header_start.checked_add(header_len).ok_or_else(invalid)?;
+    let header: CacheHeader = from_slice(map.get(header_start..trie_start).ok_or_else(invalid)?)?;
+    let checksum = crc32fast::hash(&map[trie_start..]);
+    header.validate(checksum, private_domains, local_file, remote_urls)?;
+
+    let trie = FlatTrie::new(TrieBytes::Mapped(map), trie_start).ok_or_else(invalid)?;
+    let rules = Rules {
+        trie,
+        version: header.list_version.clone(),
+    };
+    Ok((rules, header.stamp()))
+}
+
+pub fn get_tld_from_local_file<O>(local_file_path: O, private_domain: bool) -> Result<SuffixList>
 where
     O: Into<Option<String>>,
 {
@@ -27,7 +286,7 @@ where
         debug!("Trying using local public suffix file");
         let f = read_to_string(local_file)?;
         match super::parse_public_suffix_list(&f, private_domain) {
-            Ok(list) if !list.is_empty() => Ok(list),
+            Ok(list) if !list.rules.is_empty() => Ok(list),
             _ => Err(TldExtractError::Io(std::io::Error::from(
                 std::io::ErrorKind::InvalidData,
             ))),
@@ -39,24 +298,121 @@ where
     }
 }
 
-pub fn set_tld_cache<'a, O>(cache_path: O, tld_cache: &HashSet<String>) -> Result<()>
-where
-    O: Into<Option<&'a str>>,
-{
-    let cache_path = cache_path.into().unwrap_or(".tld_cache");
-    let data = to_vec(tld_cache).expect("cannot serialize tld cache");
-    let mut f = OpenOptions::new()
-        .truncate(true)
-        .write(true)
+pub fn set_tld_cache<P: AsRef<Path>>(
+    cache_path: P,
+    loaded: &LoadedRules,
+    private_domains: bool,
+    format: CacheFormat,
+) -> Result<()> {
+    let header = |checksum| CacheHeader {
+        format_version: CACHE_FORMAT_VERSION,
+        private_domains,
+        source: loaded.source,
+        location: loaded.location.clone(),
+        list_version: loaded.rules.version.clone(),
+        written_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs()),
+        checksum,
+        etag: loaded.validators.etag.clone(),
+        last_modified: loaded.validators.last_modified.clone(),
+    };
+
+    let data = match format {
+        CacheFormat::Binary => {
+            let trie = loaded.rules.trie.as_bytes();
+            let header = to_vec(&header(crc32fast::hash(trie)))?;
+            let mut data = Vec::with_capacity(BINARY_MAGIC.len() + 4 + header.len() + trie.len());
+            data.extend_from_slice(BINARY_MAGIC);
+            data.extend_from_slice(&(header.len() as u32).to_le_bytes());
+            data.extend_from_slice(&header);
+            data.extend_from_slice(trie);
+            data
+        }
+        CacheFormat::Json => {
+            let mut icann = Vec::new();
+            let mut private = Vec::new();
+            for (rule, kind) in loaded.rules.trie.rules() {
+                match kind {
+                    SuffixKind::Private => private.push(rule),
+                    _ => icann.push(rule),
+                }
+            }
+            icann.sort();
+            private.sort();
+            let file = CacheFile {
+                header: header(CacheFile::checksum(&icann, &private)),
+                icann,
+                private,
+            };
+            to_vec(&file)?
+        }
+    };
+    write_atomically(cache_path.as_ref(), &data)
+}
+
+/// Replace the file at `path` so that readers see either the old or the new
+/// content, never a partial write.
+///
+/// Writers sharing a path are serialized by an advisory lock on `<path>.lock`.
+/// The data goes to a temporary file in the same directory, which is synced
+/// and then renamed over `path`.
+///
+/// Windows refuses to replace a file that is memory mapped, by this process
+/// or another one. The old cache is then kept, it is written again by the
+/// next update.
+fn write_atomically(path: &Path, data: &[u8]) -> Result<()> {
+    let file_name = match path.file_name() {
+        Some(name) => name.to_string_lossy(),
+        None => {
+            return Err(TldExtractError::Io(std::io::Error::from(
+                std::io::ErrorKind::InvalidInput,
+            )))
+        }
+    };
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(dir)?;
+
+    let lock = OpenOptions::new()
         .create(true)
-        .open(cache_path)?;
-    f.write_all(&data)?;
+        .truncate(false)
+        .write(true)
+        .open(dir.join(format!("{}.lock", file_name)))?;
+    lock.lock_exclusive()?;
+
+    let tmp_path = dir.join(format!("{}.{}.tmp", file_name, process::id()));
+    let written = File::create(&tmp_path).and_then(|mut f| {
+        f.write_all(data)?;
+        f.sync_all()?;
+        fs::rename(&tmp_path, path)
+    });
+    if let Err(e) = written {
+        let _ = fs::remove_file(&tmp_path);
+        #[cfg(windows)]
+        {
+            if e.kind() == std::io::ErrorKind::PermissionDenied && path.exists() {
+                log::warn!("Keeping the cache {} in use: {}", path.display(), e);
+                return Ok(());
+            }
+        }
+        return Err(e.into());
+    }
+
+    // make the rename itself durable
+    #[cfg(unix)]
+    {
+        File::open(dir)?.sync_all()?;
+    }
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
     use std::path::PathBuf;
 
     #[test]
@@ -116,6 +472,258 @@ mod tests {
         .collect();
         let result = get_tld_from_local_file(Some(file_path.display().to_string()), true);
         assert!(result.is_ok());
-        assert!(!result.unwrap().is_empty())
+        let result = result.unwrap();
+        assert_eq!(result.rules.get("tldextract"), Some(&SuffixKind::Private));
+    }
+
+    const FORMATS: [CacheFormat; 2] = [CacheFormat::Binary, CacheFormat::Json];
+    const URLS: &[&str] = &["https://example.com/psl.dat"];
+
+    fn loaded(source: TldSource, location: Option<&str>) -> LoadedRules {
+        let mut rules = HashMap::new();
+        rules.insert("com".to_string(), SuffixKind::Icann);
+        rules.insert("*.ck".to_string(), SuffixKind::Icann);
+        rules.insert("!www.ck".to_string(), SuffixKind::Icann);
+        rules.insert("github.io".to_string(), SuffixKind::Private);
+        let list = SuffixList {
+            rules,
+            version: Some("2023-02-09_23-26-35_UTC".to_string()),
+        };
+        LoadedRules {
+            rules: list.into(),
+            source,
+            location: location.map(String::from),
+            stale: false,
+            validators: Validators::default(),
+        }
+    }
+
+    fn sorted_rules(rules: &Rules) -> Vec<(String, SuffixKind)> {
+        let mut rules = rules.trie.rules();
+        rules.sort_by(|a, b| a.0.cmp(&b.0));
+        rules
+    }
+
+    fn invalid_cache<T>(result: Result<T>) -> bool {
+        matches!(result, Err(TldExtractError::InvalidCacheError(_)))
+    }
+
+    #[test]
+    fn tld_cache_keeps_sections() {
+        let cache_path = std::env::temp_dir().join("tldextract-sections.tld_cache");
+        let cache_path = cache_path.to_str().unwrap();
+        let loaded = loaded(TldSource::Remote, Some("https://example.com/psl.dat"));
+
+        for format in FORMATS.iter() {
+            set_tld_cache(cache_path, &loaded, true, *format).unwrap();
+            let (rules, stamp) = get_tld_cache(cache_path, true, None, URLS).unwrap();
+            assert_eq!(sorted_rules(&rules), sorted_rules(&loaded.rules));
+            assert_eq!(rules.version, loaded.rules.version);
+            assert!(stamp.written_at.elapsed().unwrap() < Duration::from_secs(60));
+        }
+        std::fs::remove_file(cache_path).unwrap();
+    }
+
+    #[test]
+    fn tld_cache_keeps_validators() {
+        let cache_path = std::env::temp_dir().join("tldextract-validators.tld_cache");
+        let cache_path = cache_path.to_str().unwrap();
+        let url = "https://example.com/psl.dat";
+        let mut remote = loaded(TldSource::Remote, Some(url));
+        remote.validators = Validators {
+            etag: Some("\"v1\"".to_string()),
+            last_modified: Some("Thu, 09 Feb 2023 23:26:00 GMT".to_string()),
+        };
+
+        for format in FORMATS.iter() {
+            set_tld_cache(cache_path, &remote, false, *format).unwrap();
+            let (_, stamp) = get_tld_cache(cache_path, false, None, URLS).unwrap();
+            assert_eq!(stamp.url.as_deref(), Some(url));
+            assert_eq!(stamp.validators, remote.validators);
+            let other_urls = &["https://mirror.example.com/psl.dat"];
+            assert!(invalid_cache(get_tld_cache(
+                cache_path, false, None, other_urls
+            )));
+            assert!(invalid_cache(get_tld_cache(cache_path, false, None, &[])));
+
+            let snapshot = loaded(TldSource::Snapshot, None);
+            set_tld_cache(cache_path, &snapshot, false, *format).unwrap();
+            let (_, stamp) = get_tld_cache(cache_path, false, None, URLS).unwrap();
+            assert_eq!(stamp.url, None);
+            assert_eq!(stamp.validators, Validators::default());
+        }
+        std::fs::remove_file(cache_path).unwrap();
+    }
+
+    #[test]
+    fn tld_cache_formats() {
+        let cache_path = std::env::temp_dir().join("tldextract-formats.tld_cache");
+        let cache_path = cache_path.to_str().unwrap();
+        let loaded = loaded(TldSource::Snapshot, None);
+
+        set_tld_cache(cache_path, &loaded, false, CacheFormat::Binary).unwrap();
+        assert!(std::fs::read(cache_path).unwrap().starts_with(BINARY_MAGIC));
+        let (rules, _) = get_tld_cache(cache_path, false, None, URLS).unwrap();
+        let found = rules.trie.find("a.b.ck".rsplit('.')).map(|m| m.len);
+        assert_eq!(found, Some(2));
+
+        set_tld_cache(cache_path, &loaded, false, CacheFormat::Json).unwrap();
+        let json: serde_json::Value = from_slice(&std::fs::read(cache_path).unwrap()).unwrap();
+        assert_eq!(json["private"][0], "github.io");
+        std::fs::remove_file(cache_path).unwrap();
+    }
+
+    #[test]
+    fn tld_cache_matches_options() {
+        let cache_path = std::env::temp_dir().join("tldextract-options.tld_cache");
+        let cache_path = cache_path.to_str().unwrap();
+
+        for format in FORMATS.iter() {
+            let snapshot = loaded(TldSource::Snapshot, None);
+            set_tld_cache(cache_path, &snapshot, false, *format).unwrap();
+            assert!(get_tld_cache(cache_path, false, None, URLS).is_ok());
+            assert!(invalid_cache(get_tld_cache(cache_path, true, None, URLS)));
+            assert!(invalid_cache(get_tld_cache(
+                cache_path,
+                false,
+                Some("psl.dat"),
+                URLS
+            )));
+
+            let loaded = loaded(TldSource::LocalFile, Some("psl.dat"));
+            set_tld_cache(cache_path, &loaded, false, *format).unwrap();
+            assert!(get_tld_cache(cache_path, false, Some("psl.dat"), URLS).is_ok());
+            assert!(invalid_cache(get_tld_cache(
+                cache_path,
+                false,
+                Some("other.dat"),
+                URLS
+            )));
+            assert!(invalid_cache(get_tld_cache(cache_path, false, None, URLS)));
+        }
+        std::fs::remove_file(cache_path).unwrap();
+    }
+
+    #[test]
+    fn tld_cache_checksum() {
+        let cache_path = std::env::temp_dir().join("tldextract-checksum.tld_cache");
+        let cache_path = cache_path.to_str().unwrap();
+        let loaded = loaded(TldSource::Snapshot, None);
+
+        set_tld_cache(cache_path, &loaded, false, CacheFormat::Json).unwrap();
+        let data = std::fs::read_to_string(cache_path).unwrap();
+        std::fs::write(cache_path, data.replace("github.io", "gitlab.io")).unwrap();
+        assert!(invalid_cache(get_tld_cache(cache_path, false, None, URLS)));
+
+        set_tld_cache(cache_path, &loaded, false, CacheFormat::Binary).unwrap();
+        let mut data = std::fs::read(cache_path).unwrap();
+        *data.last_mut().unwrap() ^= 1;
+        std::fs::write(cache_path, &data).unwrap();
+        assert!(invalid_cache(get_tld_cache(cache_path, false, None, URLS)));
+        data.truncate(20);
+        std::fs::write(cache_path, &data).unwrap();
+        assert!(get_tld_cache(cache_path, false, None, URLS).is_err());
+        std::fs::remove_file(cache_path).unwrap();
+    }
+
+    #[test]
+    fn tld_cache_concurrent_writes() {
+        let cache_path = std::env::temp_dir().join("tldextract-concurrent.tld_cache");
+        let cache_path = cache_path.to_str().unwrap();
+        let snapshot = loaded(TldSource::Snapshot, None);
+        set_tld_cache(cache_path, &snapshot, false, CacheFormat::Binary).unwrap();
+
+        let threads: Vec<_> = (0..8)
+            .map(|i| {
+                let cache_path = cache_path.to_string();
+                std::thread::spawn(move || {
+                    for _ in 0..20 {
+                        if i % 2 == 0 {
+                            let loaded = loaded(TldSource::Snapshot, None);
+                            let format = FORMATS[i / 2 % 2];
+                            set_tld_cache(&cache_path[..], &loaded, false, format).unwrap();
+                        } else {
+                            get_tld_cache(&cache_path[..], false, None, URLS).unwrap();
+                        }
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let dir = std::fs::read_dir(std::env::temp_dir()).unwrap();
+        let leftovers = dir
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| name.starts_with("tldextract-concurrent.tld_cache."))
+            .filter(|name| name.ends_with(".tmp"))
+            .count();
+        assert_eq!(leftovers, 0);
+        std::fs::remove_file(cache_path).unwrap();
+        std::fs::remove_file(format!("{}.lock", cache_path)).unwrap();
+    }
+
+    #[test]
+    fn default_cache_location() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |key: &str| {
+                vars.iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| OsString::from(v))
+            }
+        };
+
+        let dir = default_cache_dir(env(&[("HOME", "/home/u")]));
+        assert_eq!(dir.as_deref(), Some(Path::new("/home/u/.cache/tldextract")));
+        let dir = default_cache_dir(env(&[("HOME", "/home/u"), ("XDG_CACHE_HOME", "/xdg")]));
+        assert_eq!(dir.as_deref(), Some(Path::new("/xdg/tldextract")));
+        let dir = default_cache_dir(env(&[("HOME", "/home/u"), ("XDG_CACHE_HOME", "xdg")]));
+        assert_eq!(dir.as_deref(), Some(Path::new("/home/u/.cache/tldextract")));
+        let dir = default_cache_dir(env(&[
+            ("HOME", "/home/u"),
+            ("XDG_CACHE_HOME", "/xdg"),
+            ("TLDEXTRACT_CACHE", "/srv/tld"),
+        ]));
+        assert_eq!(dir.as_deref(), Some(Path::new("/srv/tld")));
+        // no shared directory, such as the temporary one, stands in
+        assert_eq!(default_cache_dir(env(&[])), None);
+        let dir = default_cache_dir(env(&[("TLDEXTRACT_CACHE", "")]));
+        assert_eq!(dir, None);
+
+        let urls = |urls: &[&str]| urls.iter().map(|url| url.to_string()).collect::<Vec<_>>();
+        let (a, b) = (
+            urls(&["https://a.test/list.dat"]),
+            urls(&["https://b.test/list.dat"]),
+        );
+        let names = [
+            cache_file_name(None, None, false),
+            cache_file_name(None, None, true),
+            cache_file_name(Some("a.dat"), None, false),
+            cache_file_name(Some("a.dat"), None, true),
+            cache_file_name(Some("b.dat"), None, false),
+            cache_file_name(None, Some(&a), false),
+            cache_file_name(None, Some(&a), true),
+            cache_file_name(None, Some(&b), false),
+            cache_file_name(None, Some(&[]), false),
+        ];
+        assert_eq!(names[0], "public_suffix_list.tld_cache");
+        assert_eq!(names[1], "public_suffix_list-private.tld_cache");
+        for (i, name) in names.iter().enumerate() {
+            assert!(!names[i + 1..].contains(name));
+        }
+    }
+
+    #[test]
+    fn tld_cache_legacy_format() {
+        let cache_path = std::env::temp_dir().join("tldextract-legacy.tld_cache");
+        let cache_path = cache_path.to_str().unwrap();
+        std::fs::write(cache_path, r#"["com","co.uk"]"#).unwrap();
+        assert!(invalid_cache(get_tld_cache(cache_path, false, None, URLS)));
+
+        std::fs::write(cache_path, r#"{"icann":["com"],"private":[]}"#).unwrap();
+        assert!(invalid_cache(get_tld_cache(cache_path, false, None, URLS)));
+        std::fs::remove_file(cache_path).unwrap();
     }
 }
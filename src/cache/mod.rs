@@ -3,68 +3,456 @@ mod local;
 mod remote;
 mod snapshot;
 
-use regex::Regex;
-use std::collections::HashSet;
+use log::{debug, warn};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::SystemTime;
 
-use crate::errors::Result;
+use crate::errors::{Result, SourceFailure};
+use crate::trie::{parse_rule, FlatTrie, SuffixTrie};
+use crate::{SuffixKind, TldExtractError, TldOption, TldSource};
+use local::CacheStamp;
 
-const PUBLIC_SUFFIX_RE: &str = r"^(?P<suffix>[.*!]*\w[\S]*)";
+/// Where the remote list is downloaded from by default
+const PUBLIC_SUFFIX_LIST_URLS: &[&str] = &[
+    "https://publicsuffix.org/list/public_suffix_list.dat",
+    "https://raw.githubusercontent.com/publicsuffix/list/master/public_suffix_list.dat",
+];
 
+/// The rules of a public suffix list
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SuffixList {
+    pub rules: HashMap<String, SuffixKind>,
+    /// The version of the list, from its "// VERSION:" line
+    pub version: Option<String>,
+}
+
+/// The rules ready for lookups, and the version of the list they came from
+pub struct Rules {
+    pub trie: FlatTrie,
+    pub version: Option<String>,
+}
+
+impl From<SuffixList> for Rules {
+    fn from(list: SuffixList) -> Rules {
+        let trie: SuffixTrie = list
+            .rules
+            .iter()
+            .map(|(rule, kind)| (rule, *kind))
+            .collect();
+        Rules {
+            trie: trie.to_flat(),
+            version: list.version,
+        }
+    }
+}
+
+/// The HTTP validators of a downloaded list, sent back on the next download
+/// so the server only sends the list again if it changed
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Rules {
+    /// Remove the `exclude` rules, then add the `extra` ones as private rules,
+    /// all in public suffix list syntax. Extra rules that are left keep their
+    /// section. Also tells which of the extra rules were added, and which of
+    /// the excluded rules were there.
+    pub fn with_overrides(
+        self,
+        exclude: &[String],
+        extra: &[String],
+    ) -> (Rules, Vec<String>, Vec<String>) {
+        if exclude.is_empty() && extra.is_empty() {
+            return (self, Vec::new(), Vec::new());
+        }
+        let exclude: HashSet<&str> = exclude.iter().map(String::as_str).collect();
+        let mut excluded = Vec::new();
+        let mut kept = HashSet::new();
+        let mut trie = SuffixTrie::new();
+        for (rule, kind) in self.trie.rules() {
+            if exclude.contains(&rule[..]) {
+                excluded.push(rule);
+            } else {
+                trie.insert(&rule, kind);
+                kept.insert(rule);
+            }
+        }
+        for rule in exclude
+            .iter()
+            .filter(|rule| !excluded.iter().any(|e| e == *rule))
+        {
+            warn!("Cannot exclude {}, the loaded rules do not have it", rule);
+        }
+        excluded.sort();
+
+        let mut added = Vec::new();
+        for rule in extra {
+            if kept.insert(rule.clone()) {
+                trie.insert(rule, SuffixKind::Private);
+                added.push(rule.clone());
+            }
+        }
+        let rules = Rules {
+            trie: trie.to_flat(),
+            version: self.version,
+        };
+        (rules, added, excluded)
+    }
+}
+
+/// The rules loaded by `get_tld_cache`, and where they came from
+pub struct LoadedRules {
+    pub rules: Rules,
+    pub source: TldSource,
+    pub location: Option<String>,
+    /// The rules come from an expired local cache
+    pub stale: bool,
+    /// The validators of the remote list the rules came from
+    pub validators: Validators,
+}
+
+/// Load the rules from the first source that works, in order: the local cache,
+/// the local public suffix file, the remote list and the bundled snapshot.
+///
+/// A broken local public suffix file stops the search, unless the option
+/// allows falling back to the other sources.
+///
+/// A local cache older than the max age counts as a miss. If the option
+/// allows serving it stale, it is still used when the local public suffix
+/// file and the remote list fail, in preference to the bundled snapshot.
+/// If it was downloaded, the remote list is only downloaded again if it
+/// changed since, otherwise the cached rules are refreshed as they are.
+pub fn get_tld_cache(option: &TldOption) -> Result<LoadedRules> {
+    let mut loader = Loader::new(option);
+    if let Some(result) = loader.local() {
+        return result;
+    }
+
+    #[cfg(feature = "remote")]
+    {
+        let result = remote::get_tld_cache(option, loader.revalidate());
+        if let Some(loaded) = loader.remote(result) {
+            return Ok(loaded);
+        }
+    }
+
+    loader.fallback()
+}
+
+/// The same as `get_tld_cache`, but the remote list is downloaded on the
+/// runtime of the caller.
+#[cfg(feature = "remote")]
+pub async fn get_tld_cache_async(option: &TldOption) -> Result<LoadedRules> {
+    let mut loader = Loader::new(option);
+    if let Some(result) = loader.local() {
+        return result;
+    }
+
+    let result = remote::get_tld_cache_async(option, loader.revalidate()).await;
+    if let Some(loaded) = loader.remote(result) {
+        return Ok(loaded);
+    }
+
+    loader.fallback()
+}
+
+/// Download the remote list again, whatever the age of the local cache.
+/// The list is only downloaded if it changed since it was cached, the flag
+/// tells whether it did.
+#[cfg(feature = "remote")]
+pub fn refresh_tld_cache(option: &TldOption) -> Result<(LoadedRules, bool)> {
+    let mut loader = Loader::new(option);
+    loader.expired = local::cache_path(option).and_then(|cache_path| {
+        local::get_tld_cache(
+            cache_path,
+            option.private_domains,
+            option.local_public_suffix_file.as_deref(),
+            &remote_urls(option),
+        )
+        .ok()
+    });
+
+    let result = remote::get_tld_cache(option, loader.revalidate());
+    let modified = matches!(result, Ok((_, remote::Fetched::Modified(..))));
+    match loader.remote(result) {
+        Some(loaded) => Ok((loaded, modified)),
+        None => Err(TldExtractError::NoSourceError(loader.failures)),
+    }
+}
+
+/// Load the local public suffix file of the option alone
+pub fn get_local_file_tld_cache(option: &TldOption) -> Result<LoadedRules> {
+    let path = option.local_public_suffix_file.clone();
+    let list = local::get_tld_from_local_file(path.clone(), option.private_domains)?;
+    Ok(LoadedRules {
+        rules: list.into(),
+        source: TldSource::LocalFile,
+        location: path,
+        stale: false,
+        validators: Validators::default(),
+    })
+}
+
+/// A search for rules over the sources, see `get_tld_cache`
+struct Loader<'a> {
+    option: &'a TldOption,
+    failures: Vec<SourceFailure>,
+    /// The expired local cache, and where it was written
+    expired: Option<(Rules, CacheStamp)>,
+}
+
+impl<'a> Loader<'a> {
+    fn new(option: &'a TldOption) -> Loader<'a> {
+        Loader {
+            option,
+            failures: Vec::new(),
+            expired: None,
+        }
+    }
+
+    /// Try the local cache, then the local public suffix file.
+    /// `Some` ends the search.
+    fn local(&mut self) -> Option<Result<LoadedRules>> {
+        let option = self.option;
+        match local::cache_path(option) {
+            Some(cache_path) => {
+                if let Some(cache) = self.cache(&cache_path) {
+                    return Some(Ok(cache));
+                }
+            }
+            None => debug!("No local cache, HOME and the cache directories are unset"),
+        }
+
+        if let Some(path) = &option.local_public_suffix_file {
+            let cache = local::get_tld_from_local_file(path.clone(), option.private_domains)
+                .map(Rules::from);
+            if let Some(cache) = self.attempt(TldSource::LocalFile, &path[..], cache) {
+                return Some(Ok(cache));
+            }
+            if !option.local_file_fallback {
+                return Some(self.serve_stale());
+            }
+        }
+        None
+    }
+
+    /// Try the local cache, keeping it aside if it expired
+    fn cache(&mut self, cache_path: &Path) -> Option<LoadedRules> {
+        let option = self.option;
+        let location = cache_path.display().to_string();
+        let mut expired = None;
+        let cache = local::get_tld_cache(
+            cache_path,
+            option.private_domains,
+            option.local_public_suffix_file.as_deref(),
+            &remote_urls(option),
+        )
+        .and_then(|(rules, stamp)| {
+            let age = SystemTime::now()
+                .duration_since(stamp.written_at)
+                .unwrap_or_default();
+            match option.cache_max_age {
+                Some(max_age) if age >= max_age => {
+                    expired = Some((rules, stamp));
+                    Err(TldExtractError::InvalidCacheError(format!(
+                        "expired, written {}s ago",
+                        age.as_secs()
+                    )))
+                }
+                _ => Ok(rules),
+            }
+        });
+        let cache = self.attempt(TldSource::LocalCache, &location[..], cache);
+        self.expired = expired;
+        cache
+    }
+
+    /// The url and validators of the expired local cache, if it was downloaded
+    #[cfg(feature = "remote")]
+    fn revalidate(&self) -> Option<(&str, &Validators)> {
+        let (_, stamp) = self.expired.as_ref()?;
+        Some((stamp.url.as_deref()?, &stamp.validators))
+    }
+
+    #[cfg(feature = "remote")]
+    fn remote(&mut self, download: remote::Download) -> Option<LoadedRules> {
+        let (url, rules, validators) = match download {
+            Ok((url, remote::Fetched::Modified(list, validators))) => {
+                (url, list.into(), validators)
+            }
+            Ok((url, remote::Fetched::NotModified(validators))) => {
+                debug!("Remote TLD data not modified since cached");
+                let (rules, _) = self.expired.take()?;
+                (url, rules, validators)
+            }
+            Err(failures) => {
+                self.failures.extend(failures);
+                return None;
+            }
+        };
+        Some(LoadedRules {
+            rules,
+            source: TldSource::Remote,
+            location: Some(url),
+            stale: false,
+            validators,
+        })
+    }
+
+    /// The expired local cache if it may be served, otherwise the bundled snapshot
+    fn fallback(mut self) -> Result<LoadedRules> {
+        if self.option.serve_stale_cache && self.expired.is_some() {
+            return self.serve_stale();
+        }
+
+        let option = self.option;
+        let cache = snapshot::get_tld_cache(option.private_domains).and_then(|rules| {
+            check_snapshot_age(option)?;
+            Ok(rules)
+        });
+        if let Some(cache) = self.attempt(TldSource::Snapshot, None, cache) {
+            return Ok(cache);
+        }
+
+        Err(TldExtractError::NoSourceError(self.failures))
+    }
+
+    fn attempt<'l, L>(
+        &mut self,
+        source: TldSource,
+        location: L,
+        result: Result<Rules>,
+    ) -> Option<LoadedRules>
+    where
+        L: Into<Option<&'l str>>,
+    {
+        let location = location.into().map(String::from);
+        match result {
+            Ok(rules) => Some(LoadedRules {
+                rules,
+                source,
+                location,
+                stale: false,
+                validators: Validators::default(),
+            }),
+            Err(error) => {
+                debug!("Cannot load TLD data from {}: {}", source, error);
+                self.failures.push(SourceFailure {
+                    source,
+                    location,
+                    error,
+                });
+                None
+            }
+        }
+    }
+
+    fn serve_stale(&mut self) -> Result<LoadedRules> {
+        let failures = std::mem::take(&mut self.failures);
+        match self.expired.take() {
+            Some((rules, stamp)) if self.option.serve_stale_cache => {
+                warn!(
+                    "Serving the expired local cache, refreshing it failed: {}",
+                    TldExtractError::NoSourceError(failures)
+                );
+                Ok(LoadedRules {
+                    rules,
+                    source: TldSource::LocalCache,
+                    location: local::cache_path(self.option)
+                        .map(|cache_path| cache_path.display().to_string()),
+                    stale: true,
+                    validators: stamp.validators,
+                })
+            }
+            _ => Err(TldExtractError::NoSourceError(failures)),
+        }
+    }
+}
+
+/// Warn about, or reject, a bundled snapshot older than the option allows.
+/// A snapshot of unknown age counts as too old.
+fn check_snapshot_age(option: &TldOption) -> Result<()> {
+    let max_age = match option.snapshot_max_age {
+        Some(max_age) => max_age,
+        None => return Ok(()),
+    };
+    let age = snapshot::published_at()
+        .and_then(|published_at| SystemTime::now().duration_since(published_at).ok());
+    let reason = match age {
+        Some(age) if age <= max_age => return Ok(()),
+        Some(age) => format!(
+            "version {} is {} days old",
+            snapshot::version().unwrap_or_default(),
+            age.as_secs() / 86_400
+        ),
+        None => "its version is unknown".to_string(),
+    };
+    if option.reject_old_snapshot {
+        return Err(TldExtractError::OldSnapshotError(reason));
+    }
+    warn!("Using an outdated bundled snapshot, {}", reason);
+    Ok(())
+}
+
+/// The urls the remote list is downloaded from, none when it is turned off
 #[cfg(feature = "remote")]
-pub fn get_tld_cache<'a, O, T>(
-    cache_path: O,
-    local_file_path: T,
-    private_domains: bool,
-) -> HashSet<String>
-where
-    O: Into<Option<&'a str>>,
-    T: Into<Option<String>>,
-{
-    local::get_tld_cache(cache_path)
-        .or_else(|_| local::get_tld_from_local_file(local_file_path, private_domains))
-        .or_else(|_| remote::get_tld_cache(private_domains))
-        .or_else(|_| snapshot::get_tld_cache())
-        .unwrap()
+fn remote_urls(option: &TldOption) -> Vec<&str> {
+    match &option.remote_urls {
+        Some(urls) => urls.iter().map(String::as_str).collect(),
+        None => PUBLIC_SUFFIX_LIST_URLS.to_vec(),
+    }
 }
 
 #[cfg(not(feature = "remote"))]
-pub fn get_tld_cache<'a, O, T>(
-    cache_path: O,
-    local_file_path: T,
-    private_domains: bool,
-) -> HashSet<String>
-where
-    O: Into<Option<&'a str>>,
-    T: Into<Option<String>>,
-{
-    local::get_tld_cache(cache_path)
-        .or_else(|_| local::get_tld_from_local_file(local_file_path, private_domains))
-        .or_else(|_| snapshot::get_tld_cache())
-        .unwrap()
-}
-
-pub fn set_tld_cache<'a, O>(local_path: O, cache: &HashSet<String>) -> Result<()>
-where
-    O: Into<Option<&'a str>>,
-{
-    local::set_tld_cache(local_path, cache)
-}
-
-fn parse_public_suffix_list(list: &str, private_domain: bool) -> Result<HashSet<String>> {
-    let reg = Regex::new(PUBLIC_SUFFIX_RE).unwrap();
-
-    let list = if !private_domain {
-        list.split("// ===BEGIN PRIVATE DOMAINS===")
-            .next()
-            .unwrap_or("")
-    } else {
-        list
+fn remote_urls(_option: &TldOption) -> Vec<&str> {
+    PUBLIC_SUFFIX_LIST_URLS.to_vec()
+}
+
+pub fn snapshot_version() -> Option<&'static str> {
+    snapshot::version()
+}
+
+/// Write the loaded rules to the local cache, unless they came from there.
+pub fn set_tld_cache(option: &TldOption, loaded: &LoadedRules) -> Result<()> {
+    if loaded.source == TldSource::LocalCache {
+        return Ok(());
+    }
+    let cache_path = match local::cache_path(option) {
+        Some(cache_path) => cache_path,
+        None => return Ok(()),
     };
+    local::set_tld_cache(
+        cache_path,
+        loaded,
+        option.private_domains,
+        option.cache_format,
+    )
+}
+
+const PRIVATE_DOMAINS_MARKER: &str = "// ===BEGIN PRIVATE DOMAINS===";
+
+const VERSION_MARKER: &str = "// VERSION:";
 
-    Ok(list
+fn parse_public_suffix_list(list: &str, private_domain: bool) -> Result<SuffixList> {
+    let (icann, private) = match list.find(PRIVATE_DOMAINS_MARKER) {
+        Some(i) => list.split_at(i),
+        None => (list, ""),
+    };
+    let private = if private_domain { private } else { "" };
+
+    let version = icann
         .lines()
-        .filter(|line| !line.starts_with("//"))
-        .filter_map(|line| reg.captures(line.trim()).and_then(|cap| cap.name("suffix")))
-        .map(|suffix| suffix.as_str().to_string())
-        .collect())
+        .find_map(|line| line.strip_prefix(VERSION_MARKER))
+        .map(|version| version.trim().to_string());
+
+    let sections = [(icann, SuffixKind::Icann), (private, SuffixKind::Private)];
+    let rules = sections
+        .iter()
+        .flat_map(|&(section, kind)| section.lines().map(move |line| (line, kind)))
+        .filter_map(|(line, kind)| parse_rule(line).map(|rule| (rule.to_string(), kind)))
+        .collect();
+    Ok(SuffixList { rules, version })
 }
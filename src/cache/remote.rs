@@ -1,26 +1,199 @@
-use crate::errors::Result;
-use futures::TryFutureExt;
+use super::{SuffixList, Validators, PUBLIC_SUFFIX_LIST_URLS};
+use crate::errors::{Result, SourceFailure};
+use crate::{TldExtractError, TldOption, TldSource};
 use log::debug;
-use std::collections::HashSet;
-use tokio::runtime::Builder;
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use tokio::runtime::{Builder, Handle};
 
-const PUBLIC_SUFFIX_LIST_URLS: &'static [&'static str] = &[
-    "https://publicsuffix.org/list/public_suffix_list.dat",
-    "https://raw.githubusercontent.com/publicsuffix/list/master/public_suffix_list.dat",
-];
+const DEFAULT_USER_AGENT: &str = concat!("tldextract/", env!("CARGO_PKG_VERSION"));
 
-pub fn get_tld_cache(private_domain: bool) -> Result<HashSet<String>> {
-    debug!("Trying getting remote TLD data");
+/// The public suffix list has this section, an error page does not
+const ICANN_DOMAINS_MARKER: &str = "// ===BEGIN ICANN DOMAINS===";
 
-    let rt = Builder::new_current_thread().enable_all().build()?;
+/// A public suffix list with fewer rules is taken for a truncated or bogus
+/// download
+const MIN_RULE_COUNT: usize = 1000;
 
-    for u in PUBLIC_SUFFIX_LIST_URLS {
-        let respfut = reqwest::get(*u);
-        let contentfut = respfut.and_then(|resp| resp.bytes());
-        let content = rt.block_on(contentfut)?;
+/// What a url answered
+#[derive(Debug)]
+pub enum Fetched {
+    /// The list, along with its validators
+    Modified(SuffixList, Validators),
+    /// The list did not change since it was cached, along with its validators
+    NotModified(Validators),
+}
+
+/// The url the list was fetched from, and what it answered, or why each
+/// mirror failed
+pub type Download = std::result::Result<(String, Fetched), Vec<SourceFailure>>;
+
+/// Download the list on a runtime of its own, see `get_tld_cache_async`
+pub fn get_tld_cache(option: &TldOption, cached: Option<(&str, &Validators)>) -> Download {
+    if super::remote_urls(option).is_empty() {
+        return Err(Vec::new());
+    }
+    // blocking on a runtime from within another one panics
+    if Handle::try_current().is_ok() {
+        return Err(failure(TldExtractError::BlockingInRuntimeError));
+    }
+    let rt = Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| failure(e.into()))?;
+    rt.block_on(get_tld_cache_async(option, cached))
+}
+
+/// Download the list from the urls of the option, or the default ones,
+/// trying each in turn.
+///
+/// The url of the `cached` list is only asked for it if it changed since.
+/// Without any url, the remote source is off and fails without a failure.
+pub async fn get_tld_cache_async(
+    option: &TldOption,
+    cached: Option<(&str, &Validators)>,
+) -> Download {
+    let urls = super::remote_urls(option);
+    if urls.is_empty() {
+        return Err(Vec::new());
+    }
+    let client = http_client(option).map_err(failure)?;
+
+    let mut failures = Vec::new();
+    for url in urls {
+        debug!("Trying getting remote TLD data from {}", url);
+        let validators = cached.filter(|c| c.0 == url).map(|c| c.1);
+        match download(&client, url, validators, option.private_domains).await {
+            Ok(fetched) => return Ok((url.to_string(), fetched)),
+            Err(error) => {
+                debug!("Cannot download TLD data from {}: {}", url, error);
+                failures.push(SourceFailure {
+                    source: TldSource::Remote,
+                    location: Some(url.to_string()),
+                    error,
+                });
+            }
+        }
+    }
+    Err(failures)
+}
+
+/// A failure of the remote source as a whole
+fn failure(error: TldExtractError) -> Vec<SourceFailure> {
+    vec![SourceFailure {
+        source: TldSource::Remote,
+        location: None,
+        error,
+    }]
+}
+
+/// The client of the option, or one built from its settings
+fn http_client(option: &TldOption) -> Result<reqwest::Client> {
+    if let Some(client) = &option.http_client {
+        return Ok(client.clone());
+    }
+    let mut builder = reqwest::Client::builder()
+        .user_agent(option.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT));
+    if let Some(timeout) = option.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(timeout) = option.read_timeout {
+        builder = builder.read_timeout(timeout);
+    }
+    Ok(builder.build()?)
+}
+
+async fn download(
+    client: &reqwest::Client,
+    url: &str,
+    cached: Option<&Validators>,
+    private_domain: bool,
+) -> Result<Fetched> {
+    let mut req = client.get(url);
+    if let Some(cached) = cached {
+        if let Some(etag) = &cached.etag {
+            req = req.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            req = req.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+    let resp = req.send().await?.error_for_status()?;
+    let mut validators = validators(resp.headers());
+
+    if let Some(cached) = cached.filter(|_| resp.status() == StatusCode::NOT_MODIFIED) {
+        // a 304 may leave out the validators that did not change
+        validators.etag = validators.etag.or_else(|| cached.etag.clone());
+        validators.last_modified = validators
+            .last_modified
+            .or_else(|| cached.last_modified.clone());
+        return Ok(Fetched::NotModified(validators));
+    }
+    let content = resp.bytes().await?;
+    let list = parse_download(&String::from_utf8_lossy(&content), url, private_domain)?;
+    Ok(Fetched::Modified(list, validators))
+}
+
+fn validators(headers: &HeaderMap) -> Validators {
+    let header = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from)
+    };
+    Validators {
+        etag: header(ETAG),
+        last_modified: header(LAST_MODIFIED),
+    }
+}
+
+/// Parse a list downloaded from `url`, making sure it is one. Only the
+/// public suffix list itself has to have its full size, the lists of other
+/// urls need some rule.
+fn parse_download(body: &str, url: &str, private_domain: bool) -> Result<SuffixList> {
+    let public = PUBLIC_SUFFIX_LIST_URLS.contains(&url);
+    if public && !body.contains(ICANN_DOMAINS_MARKER) {
+        return Err(TldExtractError::InvalidListError(
+            "no ICANN section".to_string(),
+        ));
+    }
+    let list = super::parse_public_suffix_list(body, private_domain)?;
+    let min_rule_count = if public { MIN_RULE_COUNT } else { 1 };
+    if list.rules.len() < min_rule_count {
+        return Err(TldExtractError::InvalidListError(format!(
+            "only {} rules",
+            list.rules.len()
+        )));
+    }
+    Ok(list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_download_validates() {
+        let url = PUBLIC_SUFFIX_LIST_URLS[0];
+        let invalid = |body, url| {
+            matches!(
+                parse_download(body, url, false),
+                Err(TldExtractError::InvalidListError(_))
+            )
+        };
+        assert!(invalid("<html><body>502 Bad Gateway</body></html>", url));
+        assert!(invalid("// ===BEGIN ICANN DOMAINS===\ncom\nnet\n", url));
+
+        let list = std::fs::read_to_string("data/public_suffix_list.dat").unwrap();
+        assert!(parse_download(&list, url, false).is_ok());
+        assert!(invalid(&list[..list.len() / 50], url));
 
-        let buf = String::from_utf8_lossy(&*content);
-        return super::parse_public_suffix_list(&buf, private_domain);
+        // an internal list only needs a rule
+        let internal = "http://lists.internal/suffixes.dat";
+        assert!(parse_download("corp\nint.corp\n", internal, false).is_ok());
+        assert!(invalid(
+            "<html><body>502 Bad Gateway</body></html>",
+            internal
+        ));
     }
-    unreachable!("no suffix list urls")
 }
@@ -1,7277 +1,80 @@
+use super::Rules;
 use crate::errors::Result;
+use crate::trie::{FlatTrie, TrieBytes};
+use crate::TldExtractError;
 use log::debug;
-use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-pub fn get_tld_cache() -> Result<HashSet<String>> {
+/// The ICANN section of the bundled list, encoded by the build script
+static ICANN: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/snapshot_icann.trie"));
+/// Both sections of the bundled list
+static ALL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/snapshot_all.trie"));
+
+/// The "// VERSION:" line of the bundled list, or the version recorded next
+/// to it, empty if it has neither
+const VERSION: &str = env!("TLDEXTRACT_SNAPSHOT_VERSION");
+
+pub fn version() -> Option<&'static str> {
+    Some(VERSION).filter(|version| !version.is_empty())
+}
+
+/// When the bundled list was published, from the date its version starts with
+pub fn published_at() -> Option<SystemTime> {
+    version().and_then(parse_date)
+}
+
+/// Parse the "2023-02-09" of a "2023-02-09_23-26-00_UTC" version
+fn parse_date(version: &str) -> Option<SystemTime> {
+    let mut parts = version.get(..10)?.split('-').map(str::parse::<i64>);
+    let (year, month, day) = (
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    );
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // days since the unix epoch of a proleptic gregorian date
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    let secs = u64::try_from(days).ok()? * 86_400;
+    Some(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// The rules are queried straight from the binary, nothing is parsed
+pub fn get_tld_cache(private_domains: bool) -> Result<Rules> {
     debug!("Fallback, using local snapshot TLD data");
-    Ok(BUNDLED_CACHE.iter().map(|s| s.to_string()).collect())
+    let bytes = if private_domains { ALL } else { ICANN };
+    let trie = FlatTrie::new(TrieBytes::Static(bytes), 0).ok_or_else(|| {
+        TldExtractError::Io(std::io::Error::from(std::io::ErrorKind::InvalidData))
+    })?;
+    Ok(Rules {
+        trie,
+        version: version().map(String::from),
+    })
 }
 
-pub const BUNDLED_CACHE: &[&str] = &[
-    "chikuho.fukuoka.jp",
-    "\u{0c2d}\u{0c3e}\u{0c30}\u{0c24}\u{0c4d}",
-    "\u{516c}\u{53f8}.hk",
-    "pvt.ge",
-    "matsuzaki.shizuoka.jp",
-    "name.eg",
-    "tsuruga.fukui.jp",
-    "\u{0627}\u{062a}\u{0635}\u{0627}\u{0644}\u{0627}\u{062a}",
-    "in-addr.arpa",
-    "hisayama.fukuoka.jp",
-    "name.et",
-    "botanicalgarden.museum",
-    "union.aero",
-    "dell",
-    "biz.ki",
-    "owani.aomori.jp",
-    "kh.ua",
-    "wallonie.museum",
-    "medical.museum",
-    "imb.br",
-    "vald-aosta.it",
-    "rochester.museum",
-    "hisamitsu",
-    "seto.aichi.jp",
-    "\u{d55c}\u{ad6d}",
-    "pro.az",
-    "nose.osaka.jp",
-    "ayagawa.kagawa.jp",
-    "esp.br",
-    "orientexpress",
-    "okawa.fukuoka.jp",
-    "dgca.aero",
-    "nesoddtangen.no",
-    "cooking",
-    "s\u{00f8}gne.no",
-    "maibara.shiga.jp",
-    "aigo",
-    "kashihara.nara.jp",
-    "pd.it",
-    "here",
-    "lgbt",
-    "kmpsp.gov.pl",
-    "omuta.fukuoka.jp",
-    "s\u{00f8}rfold.no",
-    "naumburg.museum",
-    "association.aero",
-    "\u{65b0}\u{95fb}",
-    "sula.no",
-    "nom.fr",
-    "akkeshi.hokkaido.jp",
-    "azumino.nagano.jp",
-    "shiki.saitama.jp",
-    "goldpoint",
-    "dnp",
-    "bristol.museum",
-    "myoko.niigata.jp",
-    "m.se",
-    "yamatotakada.nara.jp",
-    "inzai.chiba.jp",
-    "yahoo",
-    "glass",
-    "mosj\u{00f8}en.no",
-    "shiranuka.hokkaido.jp",
-    "kudoyama.wakayama.jp",
-    "ashiya.fukuoka.jp",
-    "oyer.no",
-    "locker",
-    "lib.ut.us",
-    "philadelphia.museum",
-    "nakagawa.tokushima.jp",
-    "mytis.ru",
-    "vaksdal.no",
-    "hyatt",
-    "ginan.gifu.jp",
-    "tendo.yamagata.jp",
-    "kita.kyoto.jp",
-    "dell-ogliastra.it",
-    "travel",
-    "vv.it",
-    "how",
-    "hot",
-    "kawachinagano.osaka.jp",
-    "chase",
-    "beauty",
-    "tran\u{00f8}y.no",
-    "yun",
-    "kiev.ua",
-    "go.ug",
-    "okoppe.hokkaido.jp",
-    "helsinki",
-    "vallee-aoste.it",
-    "wine",
-    "unj\u{00e1}rga.no",
-    "edu.za",
-    "feedback",
-    "travelersinsurance",
-    "comsec",
-    "asso.re",
-    "barclays",
-    "\u{7fa4}\u{99ac}.jp",
-    "fidelity",
-    "ne.us",
-    "fit",
-    "crotone.it",
-    "exeter.museum",
-    "nesseby.no",
-    "coastaldefence.museum",
-    "ne.ug",
-    "wales",
-    "ishikawa.fukushima.jp",
-    "kasahara.gifu.jp",
-    "rendalen.no",
-    "brand.se",
-    "interactive.museum",
-    "id.au",
-    "asahikawa.hokkaido.jp",
-    "africamagic",
-    "uda.nara.jp",
-    "\u{0e44}\u{0e17}\u{0e22}",
-    "karmoy.no",
-    "com.qa",
-    "aero.tt",
-    "mil.ba",
-    "mil.bo",
-    "como.it",
-    "mil.br",
-    "fh.se",
-    "mil.by",
-    "ru",
-    "malbork.pl",
-    "rw",
-    "rs",
-    "hokuryu.hokkaido.jp",
-    "ascolipiceno.it",
-    "asahi.nagano.jp",
-    "re",
-    "susono.shizuoka.jp",
-    "sc.kr",
-    "ro",
-    "foundation",
-    "abbott",
-    "reisen",
-    "california.museum",
-    "ups",
-    "tobetsu.hokkaido.jp",
-    "walmart",
-    "ct.it",
-    "b\u{00e1}hcavuotna.no",
-    "gyeongbuk.kr",
-    "kagamino.okayama.jp",
-    "palermo.it",
-    "hjelmeland.no",
-    "kfh",
-    "basketball",
-    "gr.jp",
-    "lego",
-    "oppdal.no",
-    "xin",
-    "hikawa.shimane.jp",
-    "funabashi.chiba.jp",
-    "mckinsey",
-    "imari.saga.jp",
-    "niyodogawa.kochi.jp",
-    "tires",
-    "kommune.no",
-    "konsulat.gov.pl",
-    "gives",
-    "\u{0633}\u{0648}\u{0631}\u{064a}\u{0627}",
-    "\u{0633}\u{0648}\u{0631}\u{064a}\u{0629}",
-    "boston",
-    "kotoura.tottori.jp",
-    "pasadena.museum",
-    "fuchu.hiroshima.jp",
-    "nagasaki.nagasaki.jp",
-    "yamagata.ibaraki.jp",
-    "loabat.no",
-    "university.museum",
-    "\u{043e}\u{0431}\u{0440}.\u{0441}\u{0440}\u{0431}",
-    "\u{0642}\u{0637}\u{0631}",
-    "wake.okayama.jp",
-    "co.ci",
-    "fuchu.tokyo.jp",
-    "co.cl",
-    "co.cm",
-    "arakawa.saitama.jp",
-    "co.cr",
-    "kyonan.chiba.jp",
-    "shonai.yamagata.jp",
-    "nuoro.it",
-    "nebraska.museum",
-    "sd.cn",
-    "brindisi.it",
-    "nakayama.yamagata.jp",
-    "aircraft.aero",
-    "graz.museum",
-    "ina.saitama.jp",
-    "sn.cn",
-    "lpl",
-    "tech",
-    "ullensaker.no",
-    "volgograd.ru",
-    "\u{5343}\u{8449}.jp",
-    "eng.pro",
-    "krasnoyarsk.ru",
-    "nichinan.tottori.jp",
-    "vladimir.su",
-    "menu",
-    "supersport",
-    "otobe.hokkaido.jp",
-    "skjak.no",
-    "6.bg",
-    "hammerfest.no",
-    "karelia.ru",
-    "honda",
-    "\u{5e83}\u{5cf6}.jp",
-    "museum.no",
-    "new",
-    "tips",
-    "nagareyama.chiba.jp",
-    "jaguar",
-    "org.do",
-    "org.dm",
-    "tamayu.shimane.jp",
-    "com.mk",
-    "org.dz",
-    "med",
-    "saku.nagano.jp",
-    "council.aero",
-    "fail",
-    "archaeological.museum",
-    "goodhands",
-    "gs.fm.no",
-    "group.aero",
-    "\u{0639}\u{0631}\u{0628}",
-    "piw.gov.pl",
-    "sn\u{00e5}ase.no",
-    "name.cy",
-    "biz.mv",
-    "biz.mw",
-    "heimatunduhren.museum",
-    "bihoro.hokkaido.jp",
-    "natura",
-    "nationwide",
-    "cri.nz",
-    "cards",
-    "rybnik.pl",
-    "qld.gov.au",
-    "kami.miyagi.jp",
-    "\u{6559}\u{80b2}.hk",
-    "abruzzo.it",
-    "hita.oita.jp",
-    "nrw.museum",
-    "choshi.chiba.jp",
-    "country",
-    "nowruz",
-    "forli-cesena.it",
-    "atsuma.hokkaido.jp",
-    "frogn.no",
-    "wloclawek.pl",
-    "drangedal.no",
-    "kitagawa.miyazaki.jp",
-    "aviation.museum",
-    "mihama.chiba.jp",
-    "chrome",
-    "cc.va.us",
-    "cc.tx.us",
-    "frl",
-    "heroy.more-og-romsdal.no",
-    "gj\u{00f8}vik.no",
-    "\u{98de}\u{5229}\u{6d66}",
-    "asakawa.fukushima.jp",
-    "minamioguni.kumamoto.jp",
-    "sydney",
-    "fm.br",
-    "birthplace.museum",
-    "forum.hu",
-    "oketo.hokkaido.jp",
-    "hashimoto.wakayama.jp",
-    "bible.museum",
-    "cc.sc.us",
-    "andria-barletta-trani.it",
-    "hotel.lk",
-    "tsk.ru",
-    "inawashiro.fukushima.jp",
-    "\u{0915}\u{0949}\u{092e}",
-    "pi.it",
-    "bj.cn",
-    "urbinopesaro.it",
-    "memorial",
-    "dhl",
-    "shimabara.nagasaki.jp",
-    "agdenes.no",
-    "\u{6c96}\u{7e04}.jp",
-    "odda.no",
-    "k12.ct.us",
-    "\u{0627}\u{0644}\u{0633}\u{0639}\u{0648}\u{062f}\u{064a}\u{0629}",
-    "n\u{00e5}\u{00e5}mesjevuemie.no",
-    "ebiz.tw",
-    "higashimatsuyama.saitama.jp",
-    "chocolate.museum",
-    "sh.cn",
-    "musashimurayama.tokyo.jp",
-    "\u{0441}\u{0430}\u{0439}\u{0442}",
-    "web.tj",
-    "oshino.yamanashi.jp",
-    "cranbrook.museum",
-    "web.tr",
-    "\u{00f8}ystre-slidre.no",
-    "okuizumo.shimane.jp",
-    "nakanoto.ishikawa.jp",
-    "yoshikawa.saitama.jp",
-    "gs.sf.no",
-    "kamakura.kanagawa.jp",
-    "edu.tm",
-    "omasvuotna.no",
-    "echizen.fukui.jp",
-    "mopar",
-    "higashishirakawa.gifu.jp",
-    "awaji.hyogo.jp",
-    "tas.gov.au",
-    "airguard.museum",
-    "hiranai.aomori.jp",
-    "bnl",
-    "edu.tt",
-    "shinjo.okayama.jp",
-    "shiga.jp",
-    "skierva.no",
-    "railroad.museum",
-    "yaese.okinawa.jp",
-    "furudono.fukushima.jp",
-    "taiki.hokkaido.jp",
-    "murmansk.su",
-    "fukuoka.jp",
-    "attorney",
-    "okazaki.aichi.jp",
-    "crown",
-    "valledaosta.it",
-    "tsuiki.fukuoka.jp",
-    "fox",
-    "tcm.museum",
-    "lib.fl.us",
-    "foo",
-    "evenassi.no",
-    "nom.za",
-    "dental",
-    "richardli",
-    "quebec.museum",
-    "na.it",
-    "com.sy",
-    "chino.nagano.jp",
-    "delaware.museum",
-    "com.st",
-    "com.sv",
-    "com.sh",
-    "com.sl",
-    "com.sn",
-    "com.so",
-    "com.sa",
-    "com.sb",
-    "com.sc",
-    "com.sd",
-    "com.sg",
-    "consulting",
-    "cc.md.us",
-    "s\u{00e1}lat.no",
-    "brescia.it",
-    "mba",
-    "osaka.jp",
-    "fire",
-    "mil.lv",
-    "ivgu.no",
-    "kitagawa.kochi.jp",
-    "estate.museum",
-    "zoology.museum",
-    "stj\u{00f8}rdal.no",
-    "support",
-    "sandnessj\u{00f8}en.no",
-    "\u{4e00}\u{53f7}\u{5e97}",
-    "khakassia.su",
-    "call",
-    "ibara.okayama.jp",
-    "modum.no",
-    "asn.au",
-    "msk.ru",
-    "media.pl",
-    "nikko.tochigi.jp",
-    "\u{0627}\u{0628}\u{0648}\u{0638}\u{0628}\u{064a}",
-    "hino.tokyo.jp",
-    "vanguard",
-    "nf.ca",
-    "chosei.chiba.jp",
-    "surgut.ru",
-    "divtasvuodna.no",
-    "q.bg",
-    "dealer",
-    "pp.az",
-    "umig.gov.pl",
-    "sekikawa.niigata.jp",
-    "cyou",
-    "actor",
-    "stuttgart.museum",
-    "ferrari",
-    "naturhistorisches.museum",
-    "jmp",
-    "firm.in",
-    "tn.us",
-    "bando.ibaraki.jp",
-    "britishcolumbia.museum",
-    "sakai.ibaraki.jp",
-    "tom.ru",
-    "lib.mn.us",
-    "moriyama.shiga.jp",
-    "press.se",
-    "cesenaforli.it",
-    "decorativearts.museum",
-    "mykolaiv.ua",
-    "\u{05d9}\u{05e8}\u{05d5}\u{05e9}\u{05dc}\u{05d9}\u{05dd}.museum",
-    "iruma.saitama.jp",
-    "isshiki.aichi.jp",
-    "khmelnitskiy.ua",
-    "tsuno.miyazaki.jp",
-    "urasoe.okinawa.jp",
-    "bostik",
-    "\u{043a}\u{043e}\u{043c}",
-    "trentino-altoadige.it",
-    "vet",
-    "ikoma.nara.jp",
-    "co.mg",
-    "co.me",
-    "oldnavy",
-    "co.ma",
-    "valley.museum",
-    "yokoze.saitama.jp",
-    "co.mw",
-    "co.mu",
-    "rec.ro",
-    "t.se",
-    "kyotango.kyoto.jp",
-    "komoro.nagano.jp",
-    "exhibition.museum",
-    "tohma.hokkaido.jp",
-    "schoenbrunn.museum",
-    "lardal.no",
-    "team",
-    "banamex",
-    "yoita.niigata.jp",
-    "sandnessjoen.no",
-    "fc.it",
-    "presse.fr",
-    "ito.shizuoka.jp",
-    "med.pro",
-    "y.bg",
-    "rocher",
-    "\u{98df}\u{54c1}",
-    "nishio.aichi.jp",
-    "columbia.museum",
-    "\u{30d5}\u{30a1}\u{30c3}\u{30b7}\u{30e7}\u{30f3}",
-    "nx.cn",
-    "l\u{00f8}renskog.no",
-    "\u{043e}\u{0440}\u{0433}",
-    "sorfold.no",
-    "balestrand.no",
-    "logistics.aero",
-    "skedsmokorset.no",
-    "bw",
-    "marumori.miyagi.jp",
-    "love",
-    "gov.dm",
-    "gov.do",
-    "ug",
-    "smola.no",
-    "yamamoto.miyagi.jp",
-    "gov.dz",
-    "rc.it",
-    "bifuka.hokkaido.jp",
-    "name.az",
-    "gok.pk",
-    "chijiwa.nagasaki.jp",
-    "nl.ca",
-    "nnov.ru",
-    "pc.pl",
-    "miki.hyogo.jp",
-    "cc.al.us",
-    "k12.oh.us",
-    "alessandria.it",
-    "andoy.no",
-    "tv.tz",
-    "friuli-vegiulia.it",
-    "fresenius",
-    "space.museum",
-    "tv.tr",
-    "tuscany.it",
-    "sklep.pl",
-    "mari.ru",
-    "ac.ru",
-    "\u{09ac}\u{09be}\u{0982}\u{09b2}\u{09be}",
-    "ac.rw",
-    "ac.rs",
-    "ge.it",
-    "ne.jp",
-    "omura.nagasaki.jp",
-    "hurum.no",
-    "mutuelle",
-    "misato.saitama.jp",
-    "kikuchi.kumamoto.jp",
-    "takaoka.toyama.jp",
-    "date",
-    "elvendrell.museum",
-    "meiwa.gunma.jp",
-    "epost",
-    "sz",
-    "sy",
-    "sx",
-    "sr",
-    "cbn",
-    "sv",
-    "su",
-    "st",
-    "sk",
-    "hitachinaka.ibaraki.jp",
-    "si",
-    "sh",
-    "so",
-    "sn",
-    "sm",
-    "sl",
-    "sc",
-    "sb",
-    "sa",
-    "\u{4e09}\u{91cd}.jp",
-    "sg",
-    "se",
-    "sd",
-    "leasing.aero",
-    "chernigov.ua",
-    "trana.no",
-    "brunel.museum",
-    "showa.fukushima.jp",
-    "aero.mv",
-    "frankfurt.museum",
-    "jio",
-    "copenhagen.museum",
-    "\u{00f8}rland.no",
-    "\u{0441}\u{0440}\u{0431}",
-    "cc.ca.us",
-    "nowaruda.pl",
-    "naka.hiroshima.jp",
-    "midori.chiba.jp",
-    "showa.gunma.jp",
-    "geisei.kochi.jp",
-    "web.ve",
-    "rg.it",
-    "gokase.miyazaki.jp",
-    "okuma.fukushima.jp",
-    "lib.az.us",
-    "minamiaiki.nagano.jp",
-    "ogi.saga.jp",
-    "ashikaga.tochigi.jp",
-    "ichinomiya.aichi.jp",
-    "nobeoka.miyazaki.jp",
-    "matsue.shimane.jp",
-    "shimonoseki.yamaguchi.jp",
-    "mantova.it",
-    "ohda.shimane.jp",
-    "d\u{00f8}nna.no",
-    "air.museum",
-    "omitama.ibaraki.jp",
-    "basel.museum",
-    "shibecha.hokkaido.jp",
-    "training",
-    "gouv.km",
-    "cc.ok.us",
-    "vestv\u{00e5}g\u{00f8}y.no",
-    "laakesvuemie.no",
-    "cc.la.us",
-    "nakasatsunai.hokkaido.jp",
-    "iveco",
-    "onl",
-    "ong",
-    "one",
-    "voto",
-    "vote",
-    "ogliastra.it",
-    "open",
-    "tec.ve",
-    "city",
-    "toyama.toyama.jp",
-    "fujikawa.shizuoka.jp",
-    "kvalsund.no",
-    "castle.museum",
-    "citi",
-    "ak.us",
-    "tgory.pl",
-    "com.ua",
-    "com.ug",
-    "lerdal.no",
-    "tranby.no",
-    "iwata.shizuoka.jp",
-    "com.uz",
-    "com.uy",
-    "kitahiroshima.hokkaido.jp",
-    "fl\u{00e5}.no",
-    "jolster.no",
-    "rehab",
-    "kanra.gunma.jp",
-    "hadano.kanagawa.jp",
-    "college",
-    "sukumo.kochi.jp",
-    "town.museum",
-    "mil.no",
-    "\u{4e16}\u{754c}",
-    "mil.ng",
-    "dominic.ua",
-    "mil.nz",
-    "takinoue.hokkaido.jp",
-    "vlog.br",
-    "sas",
-    "suisse.museum",
-    "kashiwazaki.niigata.jp",
-    "vikna.no",
-    "miyashiro.saitama.jp",
-    "meloy.no",
-    "onga.fukuoka.jp",
-    "joso.ibaraki.jp",
-    "tsuruta.aomori.jp",
-    "sor-varanger.no",
-    "rightathome",
-    "sanda.hyogo.jp",
-    "archi",
-    "kamijima.ehime.jp",
-    "kitaura.miyazaki.jp",
-    "krym.ua",
-    "*.zw",
-    "nagiso.nagano.jp",
-    "axa",
-    "hakodate.hokkaido.jp",
-    "*.zm",
-    "yasuda.kochi.jp",
-    "soma.fukushima.jp",
-    "org.vn",
-    "b\u{00e6}rum.no",
-    "mil.km",
-    "muika.niigata.jp",
-    "jpmorgan",
-    "shop.pl",
-    "hockey",
-    "tado.mie.jp",
-    "pruszkow.pl",
-    "trentino.it",
-    "konyvelo.hu",
-    "turin.it",
-    "swinoujscie.pl",
-    "kamikawa.hokkaido.jp",
-    "kuban.ru",
-    "prime",
-    "\u{7d44}\u{7ec7}.hk",
-    "uz",
-    "hamada.shimane.jp",
-    "vision",
-    "unbi.ba",
-    "tomsk.ru",
-    "biev\u{00e1}t.no",
-    "surgery",
-    "futsu.nagasaki.jp",
-    "yahiko.niigata.jp",
-    "weibo",
-    "gamvik.no",
-    "co.om",
-    "misawa.aomori.jp",
-    "tjome.no",
-    "jobs",
-    "preservation.museum",
-    "kragero.no",
-    "ariake.saga.jp",
-    "friuli-v-giulia.it",
-    "mesaverde.museum",
-    "\u{9759}\u{5ca1}.jp",
-    "yoshimi.saitama.jp",
-    "kuroishi.aomori.jp",
-    "urayasu.chiba.jp",
-    "mashiko.tochigi.jp",
-    "coldwar.museum",
-    "eigersund.no",
-    "uzs.gov.pl",
-    "wa.edu.au",
-    "g\u{00e1}\u{014b}gaviika.no",
-    "\u{5c71}\u{53e3}.jp",
-    "riik.ee",
-    "photos",
-    "mobi.tz",
-    "theguardian",
-    "ggee",
-    "mobi.tt",
-    "\u{0b87}\u{0ba8}\u{0bcd}\u{0ba4}\u{0bbf}\u{0baf}\u{0bbe}",
-    "schmidt",
-    "org.ht",
-    "org.hu",
-    "engineering",
-    "org.hn",
-    "org.hk",
-    "uol",
-    "tjeldsund.no",
-    "gov.jo",
-    "yokkaichi.mie.jp",
-    "canon",
-    "yahaba.iwate.jp",
-    "r\u{00e5}de.no",
-    "tysvar.no",
-    "technology",
-    "marylhurst.museum",
-    "noshiro.akita.jp",
-    "wang",
-    "shinshinotsu.hokkaido.jp",
-    "honeywell",
-    "taiki.mie.jp",
-    "joshkar-ola.ru",
-    "saintlouis.museum",
-    "dc.us",
-    "tome.miyagi.jp",
-    "k12.va.us",
-    "jcp",
-    "tm.mc",
-    "amagasaki.hyogo.jp",
-    "tm.mg",
-    "tempio-olbia.it",
-    "nango.fukushima.jp",
-    "ballooning.aero",
-    "va.no",
-    "oirm.gov.pl",
-    "yokaichiba.chiba.jp",
-    "ac.pa",
-    "fosnes.no",
-    "rest",
-    "starostwo.gov.pl",
-    "durham.museum",
-    "\u{00f8}yer.no",
-    "sic.it",
-    "ac.pr",
-    "photography.museum",
-    "gdn",
-    "numata.hokkaido.jp",
-    "ogawa.nagano.jp",
-    "winb.gov.pl",
-    "eidfjord.no",
-    "med.pa",
-    "swatch",
-    "soka.saitama.jp",
-    "furubira.hokkaido.jp",
-    "kawaue.gifu.jp",
-    "world",
-    "cam.it",
-    "intel",
-    "iida.nagano.jp",
-    "mosvik.no",
-    "aisai.aichi.jp",
-    "tvs",
-    "hotel.hu",
-    "net.vn",
-    "net.vi",
-    "bergen.no",
-    "benevento.it",
-    "scholarships",
-    "joboji.iwate.jp",
-    "nis.za",
-    "tube",
-    "fukushima.fukushima.jp",
-    "kimitsu.chiba.jp",
-    "net.ve",
-    "bjerkreim.no",
-    "tiffany",
-    "info.bb",
-    "hachinohe.aomori.jp",
-    "shouji",
-    "origins",
-    "aco",
-    "baths.museum",
-    "isa.kagoshima.jp",
-    "net.za",
-    "her\u{00f8}y.m\u{00f8}re-og-romsdal.no",
-    "north.museum",
-    "oh.us",
-    "os.hedmark.no",
-    "financial",
-    "miyawaka.fukuoka.jp",
-    "ethnology.museum",
-    "hirosaki.aomori.jp",
-    "kita.tokyo.jp",
-    "verran.no",
-    "media",
-    "shriram",
-    "val-daosta.it",
-    "iwc",
-    "b\u{00e5}d\u{00e5}ddj\u{00e5}.no",
-    "barletta-trani-andria.it",
-    "mining.museum",
-    "sayama.osaka.jp",
-    "mallorca.museum",
-    "ushiku.ibaraki.jp",
-    "yachiyo.ibaraki.jp",
-    "video.hu",
-    "ac",
-    "ae",
-    "ad",
-    "ag",
-    "af",
-    "ai",
-    "edu.dz",
-    "am",
-    "al",
-    "ao",
-    "aq",
-    "lenug.su",
-    "as",
-    "ar",
-    "au",
-    "at",
-    "tateshina.nagano.jp",
-    "ax",
-    "az",
-    "edu.dm",
-    "edu.do",
-    "olsztyn.pl",
-    "yoga",
-    "radio.br",
-    "higashiomi.shiga.jp",
-    "co.tm",
-    "co.th",
-    "suifu.ibaraki.jp",
-    "co.tt",
-    "co.tz",
-    "r\u{00f8}yken.no",
-    "gifts",
-    "com.ws",
-    "ogori.fukuoka.jp",
-    "eastcoast.museum",
-    "tr.no",
-    "aurland.no",
-    "hemsedal.no",
-    "adac",
-    "alipay",
-    "sca",
-    "scb",
-    "tours",
-    "kurate.fukuoka.jp",
-    "mil.hn",
-    "\u{0928}\u{0947}\u{091f}",
-    "rockart.museum",
-    "tz",
-    "gs.aa.no",
-    "tv",
-    "tw",
-    "tt",
-    "immobilien",
-    "tr",
-    "tn",
-    "to",
-    "tl",
-    "tm",
-    "tj",
-    "tk",
-    "th",
-    "tf",
-    "tg",
-    "td",
-    "tsuruoka.yamagata.jp",
-    "tc",
-    "voagat.no",
-    "suedtirol.it",
-    "kyiv.ua",
-    "chikujo.fukuoka.jp",
-    "casa",
-    "abbvie",
-    "wroclaw.pl",
-    "ed.jp",
-    "publ.pt",
-    "corporation.museum",
-    "nagasu.kumamoto.jp",
-    "kawamata.fukushima.jp",
-    "itami.hyogo.jp",
-    "legnica.pl",
-    "tokorozawa.saitama.jp",
-    "hikone.shiga.jp",
-    "minamiizu.shizuoka.jp",
-    "samnanger.no",
-    "kashima.kumamoto.jp",
-    "cash",
-    "choyo.kumamoto.jp",
-    "kartuzy.pl",
-    "michigan.museum",
-    "nuremberg.museum",
-    "marnardal.no",
-    "luroy.no",
-    "kasumigaura.ibaraki.jp",
-    "otoineppu.hokkaido.jp",
-    "machida.tokyo.jp",
-    "bronnoy.no",
-    "v\u{00e5}gs\u{00f8}y.no",
-    "sand\u{00f8}y.no",
-    "uonuma.niigata.jp",
-    "shingu.wakayama.jp",
-    "nayoro.hokkaido.jp",
-    "tydal.no",
-    "jewelry.museum",
-    "frosinone.it",
-    "nakagawa.hokkaido.jp",
-    "pics",
-    "pb.ao",
-    "cc.wa.us",
-    "va.us",
-    "social",
-    "rec.ve",
-    "monza-e-della-brianza.it",
-    "botanical.museum",
-    "ichinohe.iwate.jp",
-    "friuli-veneziagiulia.it",
-    "vip",
-    "pro.vn",
-    "select",
-    "co.ir",
-    "asker.no",
-    "ukiha.fukuoka.jp",
-    "co.it",
-    "history.museum",
-    "furukawa.miyagi.jp",
-    "haebaru.okinawa.jp",
-    "gyokuto.kumamoto.jp",
-    "etnedal.no",
-    "co.id",
-    "co.in",
-    "co.il",
-    "co.im",
-    "casino.hu",
-    "kamisu.ibaraki.jp",
-    "company",
-    "tur.ar",
-    "tosa.kochi.jp",
-    "bielawa.pl",
-    "mad.museum",
-    "giske.no",
-    "science",
-    "s\u{00f8}r-aurdal.no",
-    "labour.museum",
-    "ve.it",
-    "rennes\u{00f8}y.no",
-    "edunet.tn",
-    "abb",
-    "kawara.fukuoka.jp",
-    "gamo.shiga.jp",
-    "s\u{00f8}mna.no",
-    "sigdal.no",
-    "ube.yamaguchi.jp",
-    "morimachi.shizuoka.jp",
-    "yoshino.nara.jp",
-    "shikokuchuo.ehime.jp",
-    "lesja.no",
-    "svizzera.museum",
-    "br\u{00f8}nn\u{00f8}ysund.no",
-    "annefrank.museum",
-    "limanowa.pl",
-    "oppegard.no",
-    "org.jo",
-    "org.je",
-    "starhub",
-    "gov.hk",
-    "gwangju.kr",
-    "akaiwa.okayama.jp",
-    "k12.ut.us",
-    "matsushima.miyagi.jp",
-    "higashi.fukuoka.jp",
-    "trade",
-    "nanbu.tottori.jp",
-    "yandex",
-    "itv",
-    "broadway",
-    "name.my",
-    "or.pw",
-    "troitsk.su",
-    "tanabe.wakayama.jp",
-    "name.mv",
-    "kchr.ru",
-    "sa.gov.pl",
-    "ally",
-    "\u{653f}\u{52a1}",
-    "cartoonart.museum",
-    "s\u{00f8}ndre-land.no",
-    "kppsp.gov.pl",
-    "events",
-    "busan.kr",
-    "courses",
-    "otsuki.kochi.jp",
-    "tm.km",
-    "e.bg",
-    "iglesiascarbonia.it",
-    "lea\u{014b}gaviika.no",
-    "osaka",
-    "nittedal.no",
-    "ohira.miyagi.jp",
-    "perso.ht",
-    "ac.vn",
-    "hyogo.jp",
-    "ftr",
-    "in.na",
-    "ichikawamisato.yamanashi.jp",
-    "numazu.shizuoka.jp",
-    "in.ni",
-    "astrakhan.ru",
-    "creditunion",
-    "obninsk.su",
-    "kushiro.hokkaido.jp",
-    "geelvinck.museum",
-    "ecn.br",
-    "nishimera.miyazaki.jp",
-    "arboretum.museum",
-    "yono.saitama.jp",
-    "hirado.nagasaki.jp",
-    "gorge.museum",
-    "nanjo.okinawa.jp",
-    "mil.uy",
-    "basilicata.it",
-    "\u{7db2}\u{7d61}.hk",
-    "vrn.ru",
-    "tokigawa.saitama.jp",
-    "niikappu.hokkaido.jp",
-    "heritage.museum",
-    "kitchen",
-    "agrigento.it",
-    "le.it",
-    "iraq.museum",
-    "kobierzyce.pl",
-    "aaa",
-    "database.museum",
-    "ibestad.no",
-    "cc.wy.us",
-    "sel.no",
-    "assabu.hokkaido.jp",
-    "ohira.tochigi.jp",
-    "folkebibl.no",
-    "psse.gov.pl",
-    "olayangroup",
-    "somna.no",
-    "dagestan.su",
-    "hirata.fukushima.jp",
-    "tysfjord.no",
-    "takatsuki.shiga.jp",
-    "play",
-    "rel.ht",
-    "c.bg",
-    "singles",
-    "health.museum",
-    "bmd.br",
-    "chiyoda.gunma.jp",
-    "augustow.pl",
-    "matsuno.ehime.jp",
-    "\u{00e5}mli.no",
-    "0.bg",
-    "software.aero",
-    "can.museum",
-    "microsoft",
-    "edu.bz",
-    "taxi",
-    "kaisei.kanagawa.jp",
-    "golf",
-    "gold",
-    "cruises",
-    "edu.br",
-    "edu.bs",
-    "kg.kr",
-    "\u{0440}\u{0443}\u{0441}",
-    "edu.bt",
-    "edu.bh",
-    "edu.bi",
-    "edu.bo",
-    "guernsey.museum",
-    "vb.it",
-    "edu.ba",
-    "minoh.osaka.jp",
-    "wzmiuw.gov.pl",
-    "co.ve",
-    "condos",
-    "co.vi",
-    "okagaki.fukuoka.jp",
-    "barclaycard",
-    "marugame.kagawa.jp",
-    "kitakami.iwate.jp",
-    "veterinaire.km",
-    "com.is",
-    "gratis",
-    "com.iq",
-    "farsund.no",
-    "masuda.shimane.jp",
-    "ulm.museum",
-    "amur.ru",
-    "com.io",
-    "com.im",
-    "\u{9e7f}\u{5150}\u{5cf6}.jp",
-    "progressive",
-    "pilots.museum",
-    "shiksha",
-    "seirou.niigata.jp",
-    "psp.gov.pl",
-    "tondabayashi.osaka.jp",
-    "sew",
-    "esan.hokkaido.jp",
-    "ses",
-    "k12.id.us",
-    "nesodden.no",
-    "ibigawa.gifu.jp",
-    "sarufutsu.hokkaido.jp",
-    "valle-aosta.it",
-    "sex",
-    "mil.jo",
-    "uw.gov.pl",
-    "hachirogata.akita.jp",
-    "uvic.museum",
-    "so.gov.pl",
-    "slattum.no",
-    "other.nf",
-    "yamato.kanagawa.jp",
-    "leangaviika.no",
-    "ltd.gi",
-    "com.kz",
-    "cc.nd.us",
-    "vs.it",
-    "\u{00e5}krehamn.no",
-    "lib.dc.us",
-    "mol.it",
-    "comunica\u{00e7}\u{00f5}es.museum",
-    "act.edu.au",
-    "magazine.aero",
-    "ollo",
-    "celtic.museum",
-    "loan",
-    "flight.aero",
-    "community",
-    "com.ki",
-    "kids.museum",
-    "otago.museum",
-    "kashiwa.chiba.jp",
-    "laz.it",
-    "shiroishi.miyagi.jp",
-    "hiroshima.jp",
-    "nra",
-    "notteroy.no",
-    "fund",
-    "vda.it",
-    "pila.pl",
-    "contemporary.museum",
-    "national.museum",
-    "nrw",
-    "mlb",
-    "environmentalconservation.museum",
-    "mls",
-    "omaezaki.shizuoka.jp",
-    "pittsburgh.museum",
-    "hino.tottori.jp",
-    "moto",
-    "ac.kr",
-    "j.bg",
-    "aurskog-holand.no",
-    "audi",
-    "miasta.pl",
-    "seek",
-    "co.kr",
-    "nabari.mie.jp",
-    "ikeda.fukui.jp",
-    "namerikawa.toyama.jp",
-    "engerdal.no",
-    "read",
-    "ind.tn",
-    "nagi.okayama.jp",
-    "ltda",
-    "maori.nz",
-    "yamagata.jp",
-    "\u{6de1}\u{9a6c}\u{9521}",
-    "minamiawaji.hyogo.jp",
-    "sandvik",
-    "caa.aero",
-    "utashinai.hokkaido.jp",
-    "rodoy.no",
-    "tsushima.nagasaki.jp",
-    "altai.ru",
-    "vaapste.no",
-    "lavagis.no",
-    "assisi.museum",
-    "ugim.gov.pl",
-    "koga.fukuoka.jp",
-    "gripe",
-    "iwade.wakayama.jp",
-    "coop.br",
-    "turystyka.pl",
-    "bl.it",
-    "tsuga.tochigi.jp",
-    "soo.kagoshima.jp",
-    "openair.museum",
-    "horokanai.hokkaido.jp",
-    "\u{0627}\u{0644}\u{0633}\u{0639}\u{0648}\u{062f}\u{064a}\u{0647}",
-    "grosseto.it",
-    "poltava.ua",
-    "toho.fukuoka.jp",
-    "oppeg\u{00e5}rd.no",
-    "org.lb",
-    "org.lc",
-    "org.la",
-    "stange.no",
-    "org.lk",
-    "olbiatempio.it",
-    "intuit",
-    "rexroth",
-    "org.lr",
-    "org.ls",
-    "quest",
-    "org.lv",
-    "og.ao",
-    "\u{0627}\u{064a}\u{0631}\u{0627}\u{0646}.ir",
-    "org.ly",
-    "williamsburg.museum",
-    "k12.ky.us",
-    "handson.museum",
-    "takanabe.miyazaki.jp",
-    "pro.tt",
-    "sunndal.no",
-    "gov.nr",
-    "gov.ng",
-    "satx.museum",
-    "kitagata.gifu.jp",
-    "redstone",
-    "hidaka.saitama.jp",
-    "\u{00f8}rsta.no",
-    "*.nom.br",
-    "tas.au",
-    "ohtawara.tochigi.jp",
-    "ishikawa.okinawa.jp",
-    "torahime.shiga.jp",
-    "hob\u{00f8}l.no",
-    "noda.iwate.jp",
-    "geek.nz",
-    "dating",
-    "be",
-    "bf",
-    "bg",
-    "ba",
-    "bb",
-    "carbonia-iglesias.it",
-    "bm",
-    "bo",
-    "bh",
-    "bi",
-    "bj",
-    "bt",
-    "bv",
-    "kunstunddesign.museum",
-    "br",
-    "bs",
-    "by",
-    "bz",
-    "austin.museum",
-    "netflix",
-    "karuizawa.nagano.jp",
-    "melbourne",
-    "fin.ec",
-    "higashi.okinawa.jp",
-    "ruhr",
-    "eid.no",
-    "gouv.rw",
-    "ga.us",
-    "repair",
-    "dielddanuorri.no",
-    "ac.tz",
-    "locus",
-    "ac.tj",
-    "ac.th",
-    "timekeeping.museum",
-    "yurihonjo.akita.jp",
-    "ns.ca",
-    "author.aero",
-    "nichinan.miyazaki.jp",
-    "exposed",
-    "cadaques.museum",
-    "narita.chiba.jp",
-    "kurashiki.okayama.jp",
-    "yamanobe.yamagata.jp",
-    "karpacz.pl",
-    "nishikatsura.yamanashi.jp",
-    "bryansk.su",
-    "pinb.gov.pl",
-    "ogawa.ibaraki.jp",
-    "seiyo.ehime.jp",
-    "link",
-    "perugia.it",
-    "uy",
-    "aioi.hyogo.jp",
-    "us",
-    "uk",
-    "kahoku.yamagata.jp",
-    "sdn.gov.pl",
-    "higashiura.aichi.jp",
-    "ip6.arpa",
-    "ua",
-    "camp",
-    "georgia.museum",
-    "nextdirect",
-    "showtime",
-    "motegi.tochigi.jp",
-    "\u{7db2}\u{7d61}.cn",
-    "vik.no",
-    "esashi.hokkaido.jp",
-    "chat",
-    "chelyabinsk.ru",
-    "pharmacien.fr",
-    "codes",
-    "kirkenes.no",
-    "inashiki.ibaraki.jp",
-    "kurobe.toyama.jp",
-    "jcb",
-    "minamiboso.chiba.jp",
-    "habmer.no",
-    "fribourg.museum",
-    "cymru.museum",
-    "nalchik.ru",
-    "bato.tochigi.jp",
-    "yufu.oita.jp",
-    "info.nr",
-    "net.vc",
-    "larvik.no",
-    "info.ni",
-    "net.vu",
-    "info.nf",
-    "friulivgiulia.it",
-    "wolomin.pl",
-    "info.na",
-    "songdalen.no",
-    "land",
-    "web.lk",
-    "tn.it",
-    "oracle",
-    "kisarazu.chiba.jp",
-    "priv.pl",
-    "qvc",
-    "luster.no",
-    "fujikawaguchiko.yamanashi.jp",
-    "terni.it",
-    "stathelle.no",
-    "tabayama.yamanashi.jp",
-    "garden",
-    "natuurwetenschappen.museum",
-    "zgora.pl",
-    "medecin.fr",
-    "\u{798f}\u{5ca1}.jp",
-    "ehime.jp",
-    "fareast.ru",
-    "calabria.it",
-    "taobao",
-    "narviika.no",
-    "zhitomir.ua",
-    "asso.dz",
-    "video",
-    "napoli.it",
-    "b\u{00e5}tsfjord.no",
-    "k12.wa.us",
-    "saitama.saitama.jp",
-    "vic.gov.au",
-    "kawakita.ishikawa.jp",
-    "co.pn",
-    "kosai.shizuoka.jp",
-    "b\u{00f8}.telemark.no",
-    "cambridge.museum",
-    "citic",
-    "vet.br",
-    "co.pw",
-    "com.kp",
-    "tranibarlettaandria.it",
-    "com.ky",
-    "virginia.museum",
-    "foodnetwork",
-    "com.kg",
-    "flakstad.no",
-    "aaa.pro",
-    "com.km",
-    "fujisawa.kanagawa.jp",
-    "kasamatsu.gifu.jp",
-    "nishigo.fukushima.jp",
-    "casino",
-    "koto.tokyo.jp",
-    "k12.tr",
-    "monzaedellabrianza.it",
-    "\u{0628}\u{064a}\u{062a}\u{0643}",
-    "hm.no",
-    "zaporizhzhia.ua",
-    "kamiizumi.saitama.jp",
-    "hatsukaichi.hiroshima.jp",
-    "fitjar.no",
-    "cbre",
-    "tools",
-    "tree.museum",
-    "zip",
-    "deatnu.no",
-    "sc.us",
-    "auto.pl",
-    "textile.museum",
-    "next",
-    "fortworth.museum",
-    "s.bg",
-    "vgs.no",
-    "incheon.kr",
-    "pt.it",
-    "sc.ug",
-    "media.hu",
-    "sp.it",
-    "rich",
-    "tamamura.gunma.jp",
-    "williamhill",
-    "nishinomiya.hyogo.jp",
-    "baby",
-    "embaixada.st",
-    "lebesby.no",
-    "hara.nagano.jp",
-    "nagato.yamaguchi.jp",
-    "ogawa.saitama.jp",
-    "athleta",
-    "jeep",
-    "emerson",
-    "sauherad.no",
-    "schlesisches.museum",
-    "yomitan.okinawa.jp",
-    "gov.sd",
-    "gov.sg",
-    "gov.sa",
-    "gov.sc",
-    "gov.sb",
-    "gov.sl",
-    "gov.sh",
-    "entertainment.aero",
-    "gov.st",
-    "srl",
-    "gov.sy",
-    "gov.sx",
-    "akdn",
-    "bashkiria.su",
-    "\u{043c}\u{043e}\u{0441}\u{043a}\u{0432}\u{0430}",
-    "uhren.museum",
-    "rakkestad.no",
-    "malatvuopmi.no",
-    "kvam.no",
-    "kamoenai.hokkaido.jp",
-    "srt",
-    "agrar.hu",
-    "culturalcenter.museum",
-    "bar.pro",
-    "int.la",
-    "praxi",
-    "int.lk",
-    "cl.it",
-    "store.st",
-    "ac.il",
-    "ac.im",
-    "ac.in",
-    "barcelona.museum",
-    "ac.id",
-    "yoka.hyogo.jp",
-    "higashimurayama.tokyo.jp",
-    "daigo.ibaraki.jp",
-    "communications.museum",
-    "grp.lk",
-    "mukawa.hokkaido.jp",
-    "sokndal.no",
-    "kv.ua",
-    "kizu.kyoto.jp",
-    "yame.fukuoka.jp",
-    "dep.no",
-    "docs",
-    "suzu.ishikawa.jp",
-    "tempioolbia.it",
-    "koshimizu.hokkaido.jp",
-    "pol.tr",
-    "living.museum",
-    "\u{5927}\u{5206}.jp",
-    "bardu.no",
-    "burghof.museum",
-    "br.it",
-    "samukawa.kanagawa.jp",
-    "vibovalentia.it",
-    "matera.it",
-    "kpn",
-    "av.it",
-    "osen.no",
-    "lanxess",
-    "motosu.gifu.jp",
-    "pmn.it",
-    "cci.fr",
-    "lur\u{00f8}y.no",
-    "\u{66f8}\u{7c4d}",
-    "boutique",
-    "hadsel.no",
-    "wegrow.pl",
-    "corsica",
-    "correios-e-telecomunica\u{00e7}\u{00f5}es.museum",
-    "org.ni",
-    "durban",
-    "org.ng",
-    "org.na",
-    "yoshioka.gunma.jp",
-    "org.nz",
-    "red.sv",
-    "org.nr",
-    "tawaramoto.nara.jp",
-    "lib.ok.us",
-    "bir.ru",
-    "cc.ny.us",
-    "belau.pw",
-    "gov.ly",
-    "gov.lt",
-    "or.th",
-    "vestre-toten.no",
-    "abiko.chiba.jp",
-    "anthropology.museum",
-    "gov.lk",
-    "or.tz",
-    "gov.la",
-    "gov.lb",
-    "gov.lc",
-    "trentino-stirol.it",
-    "not.br",
-    "izhevsk.ru",
-    "mitou.yamaguchi.jp",
-    "farmequipment.museum",
-    "norton",
-    "nationalfirearms.museum",
-    "nm.cn",
-    "imamat",
-    "nishikata.tochigi.jp",
-    "namsskogan.no",
-    "kyoto.jp",
-    "jan-mayen.no",
-    "hitachiomiya.ibaraki.jp",
-    "morotsuka.miyazaki.jp",
-    "lib.tn.us",
-    "ot.it",
-    "ozu.ehime.jp",
-    "makinohara.shizuoka.jp",
-    "\u{0431}\u{0435}\u{043b}",
-    "lib.il.us",
-    "mibu.tochigi.jp",
-    "higashikagura.hokkaido.jp",
-    "omigawa.chiba.jp",
-    "perso.tn",
-    "gob.sv",
-    "blockbuster",
-    "natori.miyagi.jp",
-    "culture.museum",
-    "kunitachi.tokyo.jp",
-    "oyabe.toyama.jp",
-    "wakasa.fukui.jp",
-    "best",
-    "rmit",
-    "lib.gu.us",
-    "latino",
-    "linde",
-    "rennebu.no",
-    "toyo.kochi.jp",
-    "k12.ak.us",
-    "jobs.tt",
-    "aq.it",
-    "visa",
-    "minnesota.museum",
-    "miyoshi.hiroshima.jp",
-    "kurgan.ru",
-    "bio.br",
-    "tomioka.gunma.jp",
-    "mil.qa",
-    "landes.museum",
-    "honbetsu.hokkaido.jp",
-    "lc.it",
-    "\u{9ce5}\u{53d6}.jp",
-    "corvette.museum",
-    "k12.md.us",
-    "\u{5bb6}\u{96fb}",
-    "kozaki.chiba.jp",
-    "matsusaka.mie.jp",
-    "brandywinevalley.museum",
-    "net.tw",
-    "web.nf",
-    "holt\u{00e5}len.no",
-    "net.tr",
-    "web.ni",
-    "campidano-medio.it",
-    "autos",
-    "reggiocalabria.it",
-    "newjersey.museum",
-    "net.tn",
-    "net.tm",
-    "luzern.museum",
-    "net.th",
-    "reit",
-    "intl.tn",
-    "romskog.no",
-    "itano.tokushima.jp",
-    "aeg",
-    "gran.no",
-    "haugesund.no",
-    "v\u{00e5}g\u{00e5}.no",
-    "swiftcover",
-    "greta.fr",
-    "kishiwada.osaka.jp",
-    "rennesoy.no",
-    "nes.akershus.no",
-    "virtuel.museum",
-    "shimosuwa.nagano.jp",
-    "vistaprint",
-    "gs.cn",
-    "ac.ir",
-    "koge.tottori.jp",
-    "email",
-    "judaica.museum",
-    "inagawa.hyogo.jp",
-    "sor-aurdal.no",
-    "experts-comptables.fr",
-    "\u{5fae}\u{535a}",
-    "mielec.pl",
-    "komatsu",
-    "e-burg.ru",
-    "safety.aero",
-    "ci",
-    "ch",
-    "shishikui.tokushima.jp",
-    "cn",
-    "cm",
-    "cl",
-    "cc",
-    "ca",
-    "watari.miyagi.jp",
-    "cf",
-    "cd",
-    "cz",
-    "cx",
-    "cr",
-    "cw",
-    "cv",
-    "cu",
-    "co.rw",
-    "venice.it",
-    "co.rs",
-    "shimogo.fukushima.jp",
-    "h.se",
-    "lindesnes.no",
-    "com.mx",
-    "com.my",
-    "lib.id.us",
-    "satte.saitama.jp",
-    "com.ms",
-    "com.mv",
-    "com.mw",
-    "com.mt",
-    "com.mu",
-    "nordkapp.no",
-    "com.mo",
-    "com.ml",
-    "graphics",
-    "toyono.osaka.jp",
-    "com.mg",
-    "froland.no",
-    "gs.oslo.no",
-    "eus",
-    "teo.br",
-    "rindal.no",
-    "vc",
-    "juedisches.museum",
-    "ve",
-    "vg",
-    "vi",
-    "bjark\u{00f8}y.no",
-    "site",
-    "vn",
-    "trentinosuedtirol.it",
-    "mihama.wakayama.jp",
-    "ltd.cy",
-    "tattoo",
-    "yamada.iwate.jp",
-    "tula.su",
-    "karlsoy.no",
-    "cc.ak.us",
-    "trust.museum",
-    "android",
-    "chuo.osaka.jp",
-    "chuvashia.ru",
-    "posts-and-telecommunications.museum",
-    "infiniti",
-    "*.bd",
-    "toshiba",
-    "*.bn",
-    "asso.fr",
-    "off",
-    "principe.st",
-    "edu.ni",
-    "edu.ng",
-    "*.jm",
-    "kanzaki.saga.jp",
-    "audio",
-    "edu.nr",
-    "tomisato.chiba.jp",
-    "gov.qa",
-    "gs.rl.no",
-    "madrid.museum",
-    "fj.cn",
-    "lib.mi.us",
-    "fet.no",
-    "wed",
-    "komae.tokyo.jp",
-    "parliament.cy",
-    "tsunan.niigata.jp",
-    "\u{00e5}snes.no",
-    "ddr.museum",
-    "museum.tt",
-    "shobara.hiroshima.jp",
-    "aya.miyazaki.jp",
-    "\u{653f}\u{5e9c}",
-    "kharkiv.ua",
-    "tm.ro",
-    "int.ni",
-    "kadena.okinawa.jp",
-    "ven.it",
-    "amot.no",
-    "liguria.it",
-    "omachi.saga.jp",
-    "shikabe.hokkaido.jp",
-    "boston.museum",
-    "iijima.nagano.jp",
-    "emergency.aero",
-    "futaba.fukushima.jp",
-    "omi.niigata.jp",
-    "witd.gov.pl",
-    "hidaka.hokkaido.jp",
-    "in.us",
-    "ikeda.hokkaido.jp",
-    "inf.mk",
-    "minano.saitama.jp",
-    "mill.museum",
-    "in.ua",
-    "ostre-toten.no",
-    "rauma.no",
-    "tosu.saga.jp",
-    "consulting.aero",
-    "unsa.ba",
-    "shimane.jp",
-    "civilisation.museum",
-    "birdart.museum",
-    "ichikai.tochigi.jp",
-    "loans",
-    "voss.no",
-    "\u{5c71}\u{5f62}.jp",
-    "lifestyle",
-    "klodzko.pl",
-    "k12.as.us",
-    "holdings",
-    "stavropol.ru",
-    "minamitane.kagoshima.jp",
-    "krd",
-    "software",
-    "noheji.aomori.jp",
-    "kaminoyama.yamagata.jp",
-    "ismaili",
-    "ichihara.chiba.jp",
-    "dyroy.no",
-    "org.pt",
-    "taishi.hyogo.jp",
-    "org.pr",
-    "org.ps",
-    "net.om",
-    "org.py",
-    "org.pf",
-    "org.pe",
-    "org.pa",
-    "org.pn",
-    "org.pl",
-    "org.pk",
-    "org.ph",
-    "hongo.hiroshima.jp",
-    "akagi.shimane.jp",
-    "volvo",
-    "tp.it",
-    "pro.pr",
-    "s\u{00f8}r-varanger.no",
-    "irish",
-    "kiyokawa.kanagawa.jp",
-    "vadso.no",
-    "int",
-    "life",
-    "!city.nagoya.jp",
-    "kanazawa.ishikawa.jp",
-    "kerrylogistics",
-    "skanit.no",
-    "ink",
-    "namsos.no",
-    "ing",
-    "g\u{00e1}ls\u{00e1}.no",
-    "starnberg.museum",
-    "k12.il.us",
-    "sykkylven.no",
-    "kami.kochi.jp",
-    "satsumasendai.kagoshima.jp",
-    "hirakata.osaka.jp",
-    "osoyro.no",
-    "\u{798f}\u{4e95}.jp",
-    "omi.nagano.jp",
-    "aetna",
-    "from.hr",
-    "genting",
-    "ina.ibaraki.jp",
-    "med.ht",
-    "namegata.ibaraki.jp",
-    "takayama.gifu.jp",
-    "maniwa.okayama.jp",
-    "oki.fukuoka.jp",
-    "takatsuki.osaka.jp",
-    "idv.tw",
-    "nsw.au",
-    "bike",
-    "n.se",
-    "mp.br",
-    "bomlo.no",
-    "grane.no",
-    "inami.toyama.jp",
-    "rahkkeravju.no",
-    "casadelamoneda.museum",
-    "\u{500b}\u{4eba}.hk",
-    "\u{6ecb}\u{8cc0}.jp",
-    "\u{5ca1}\u{5c71}.jp",
-    "lib.co.us",
-    "tickets",
-    "oizumi.gunma.jp",
-    "gs.vf.no",
-    "tamakawa.fukushima.jp",
-    "portlligat.museum",
-    "ikeda.nagano.jp",
-    "yoro.gifu.jp",
-    "mil.st",
-    "sasebo.nagasaki.jp",
-    "kita.osaka.jp",
-    "mil.sy",
-    "dish",
-    "*.sapporo.jp",
-    "guge",
-    "eurovision",
-    "\u{0633}\u{0648}\u{062f}\u{0627}\u{0646}",
-    "mil.sh",
-    "km.ua",
-    "discovery.museum",
-    "\u{043f}\u{0440}.\u{0441}\u{0440}\u{0431}",
-    "nomi.ishikawa.jp",
-    "ono.hyogo.jp",
-    "test.tj",
-    "net.ru",
-    "catering.aero",
-    "net.rw",
-    "\u{6771}\u{4eac}.jp",
-    "hyundai",
-    "protection",
-    "\u{5948}\u{826f}.jp",
-    "sakuragawa.ibaraki.jp",
-    "study",
-    "gle",
-    "bnpparibas",
-    "alaska.museum",
-    "messina.it",
-    "tabuse.yamaguchi.jp",
-    "fot.br",
-    "kouhoku.saga.jp",
-    "total",
-    "\u{7d44}\u{7e54}.tw",
-    "tonosho.kagawa.jp",
-    "comcast",
-    "tj\u{00f8}me.no",
-    "\u{0627}\u{06cc}\u{0631}\u{0627}\u{0646}",
-    "roma.museum",
-    "fed.us",
-    "takasaki.gunma.jp",
-    "verbania.it",
-    "archaeology.museum",
-    "\u{0627}\u{0631}\u{0627}\u{0645}\u{0643}\u{0648}",
-    "honefoss.no",
-    "\u{5bae}\u{57ce}.jp",
-    "arkhangelsk.ru",
-    "il.us",
-    "work",
-    "aeroclub.aero",
-    "ichikawa.hyogo.jp",
-    "tsukui.kanagawa.jp",
-    "ouda.nara.jp",
-    "bunkyo.tokyo.jp",
-    "cc.ky.us",
-    "kawagoe.saitama.jp",
-    "lg.jp",
-    "dellogliastra.it",
-    "arteducation.museum",
-    "kosaka.akita.jp",
-    "macys",
-    "com.om",
-    "brasil.museum",
-    "re.kr",
-    "boats",
-    "pizza",
-    "uri.arpa",
-    "itayanagi.aomori.jp",
-    "manno.kagawa.jp",
-    "kr.it",
-    "lat",
-    "law",
-    "naroy.no",
-    "airtraffic.aero",
-    "chernihiv.ua",
-    "smolensk.ru",
-    "green",
-    "salon",
-    "shiraoka.saitama.jp",
-    "isla.pr",
-    "office",
-    "forsand.no",
-    "gop.pk",
-    "ogano.saitama.jp",
-    "gausdal.no",
-    "gjesdal.no",
-    "porsangu.no",
-    "adm.br",
-    "vladivostok.ru",
-    "gov.lv",
-    "coffee",
-    "safe",
-    "band",
-    "gov.lr",
-    "kostroma.ru",
-    "lg.ua",
-    "bank",
-    "edu.lk",
-    "edu.la",
-    "edu.lb",
-    "edu.lc",
-    "rocks",
-    "edu.ly",
-    "movistar",
-    "edu.lr",
-    "edu.lv",
-    "ck.ua",
-    "nv.us",
-    "md.ci",
-    "kamikawa.hyogo.jp",
-    "minamimaki.nagano.jp",
-    "\u{0434}\u{0435}\u{0442}\u{0438}",
-    "building.museum",
-    "gov.ws",
-    "ggf.br",
-    "lucania.it",
-    "veneto.it",
-    "\u{9999}\u{6e2f}",
-    "glass.museum",
-    "badajoz.museum",
-    "ro.it",
-    "seika.kyoto.jp",
-    "minamifurano.hokkaido.jp",
-    "ntt",
-    "gs.jan-mayen.no",
-    "jetzt",
-    "mizumaki.fukuoka.jp",
-    "tiaa",
-    "hanawa.fukushima.jp",
-    "honai.ehime.jp",
-    "kaneyama.yamagata.jp",
-    "frosta.no",
-    "kiyosato.hokkaido.jp",
-    "campania.it",
-    "sa.edu.au",
-    "k-uralsk.ru",
-    "miners.museum",
-    "tm.pl",
-    "ac.mu",
-    "ac.mw",
-    "toga.toyama.jp",
-    "veg\u{00e5}rshei.no",
-    "kiso.nagano.jp",
-    "ac.ma",
-    "trentinos-tirol.it",
-    "ac.me",
-    "kamitonda.wakayama.jp",
-    "\u{7f51}\u{7edc}.cn",
-    "kitaaiki.nagano.jp",
-    "nishiaizu.fukushima.jp",
-    "kiwi",
-    "pv.it",
-    "sande.more-og-romsdal.no",
-    "ulan-ude.ru",
-    "royrvik.no",
-    "edu.az",
-    "reklam.hu",
-    "lib.pa.us",
-    "cc.ks.us",
-    "takehara.hiroshima.jp",
-    "target",
-    "gmina.pl",
-    "sasayama.hyogo.jp",
-    "nhs.uk",
-    "denmark.museum",
-    "yamanouchi.nagano.jp",
-    "shimoichi.nara.jp",
-    "lib.ga.us",
-    "luxury",
-    "\u{0627}\u{0645}\u{0627}\u{0631}\u{0627}\u{062a}",
-    "*.nagoya.jp",
-    "net.ma",
-    "avocat.fr",
-    "net.me",
-    "k12.mi.us",
-    "net.mk",
-    "net.ml",
-    "l\u{00e1}hppi.no",
-    "net.mo",
-    "ptz.ru",
-    "net.ms",
-    "net.mt",
-    "net.mu",
-    "net.mv",
-    "net.mw",
-    "net.mx",
-    "net.my",
-    "sb.ua",
-    "mediocampidano.it",
-    "org.ro",
-    "webcam",
-    "yuki.ibaraki.jp",
-    "lefrak",
-    "ass.km",
-    "lib.ar.us",
-    "pro.na",
-    "karumai.iwate.jp",
-    "cn.it",
-    "voting",
-    "kvits\u{00f8}y.no",
-    "film.museum",
-    "trentino-sudtirol.it",
-    "arts.nf",
-    "r\u{00f8}yrvik.no",
-    "zaporizhzhe.ua",
-    "us.na",
-    "atami.shizuoka.jp",
-    "meeres.museum",
-    "latina.it",
-    "sakaiminato.tottori.jp",
-    "planetarium.museum",
-    "neustar",
-    "malselv.no",
-    "lviv.ua",
-    "\u{0645}\u{0644}\u{064a}\u{0633}\u{064a}\u{0627}",
-    "stv.ru",
-    "minami.tokushima.jp",
-    "do",
-    "dm",
-    "dj",
-    "dk",
-    "plc.co.im",
-    "de",
-    "dz",
-    "accident-prevention.aero",
-    "extraspace",
-    "chanel",
-    "spy.museum",
-    "delmenhorst.museum",
-    "slupsk.pl",
-    "dupont",
-    "vologda.ru",
-    "klepp.no",
-    "aibetsu.hokkaido.jp",
-    "lom.it",
-    "coop.ht",
-    "!city.kawasaki.jp",
-    "observer",
-    "bearalvahki.no",
-    "fukushima.jp",
-    "sc.cn",
-    "kamiamakusa.kumamoto.jp",
-    "analytics",
-    "mo-i-rana.no",
-    "\u{624b}\u{673a}",
-    "salem.museum",
-    "abira.hokkaido.jp",
-    "yotsukaido.chiba.jp",
-    "ar.it",
-    "newmexico.museum",
-    "mizuho.tokyo.jp",
-    "kawai.iwate.jp",
-    "lv.ua",
-    "2.bg",
-    "\u{9999}\u{5ddd}.jp",
-    "wf",
-    "tonaki.okinawa.jp",
-    "r\u{00e5}holt.no",
-    "mobara.chiba.jp",
-    "uryu.hokkaido.jp",
-    "kherson.ua",
-    "ws",
-    "newyork.museum",
-    "ms.us",
-    "plc.ly",
-    "fage",
-    "com",
-    "org.ec",
-    "birkenes.no",
-    "nakai.kanagawa.jp",
-    "\u{30af}\u{30e9}\u{30a6}\u{30c9}",
-    "yamaga.kumamoto.jp",
-    "hofu.yamaguchi.jp",
-    "k12.ms.us",
-    "varese.it",
-    "g12.br",
-    "yuu.yamaguchi.jp",
-    "info.hu",
-    "info.ht",
-    "futtsu.chiba.jp",
-    "net.py",
-    "muroran.hokkaido.jp",
-    "test.ru",
-    "net.ps",
-    "net.pr",
-    "karatsu.saga.jp",
-    "pesarourbino.it",
-    "net.pk",
-    "net.ph",
-    "net.pn",
-    "hanamigawa.chiba.jp",
-    "net.pl",
-    "net.pa",
-    "isa.us",
-    "net.pe",
-    "h\u{00e5}.no",
-    "\u{00f8}stre-toten.no",
-    "takatori.nara.jp",
-    "balat.no",
-    "vf.no",
-    "alabama.museum",
-    "lib.de.us",
-    "az.us",
-    "aig",
-    "property",
-    "olayan",
-    "\u{9752}\u{68ee}.jp",
-    "priv.no",
-    "wios.gov.pl",
-    "l\u{00f8}dingen.no",
-    "sakura",
-    "komatsu.ishikawa.jp",
-    "k12.gu.us",
-    "matsumoto.nagano.jp",
-    "raid",
-    "\u{5728}\u{7ebf}",
-    "juniper",
-    "vagsoy.no",
-    "dr\u{00f8}bak.no",
-    "oirase.aomori.jp",
-    "yokote.akita.jp",
-    "mx.na",
-    "sasaguri.fukuoka.jp",
-    "contact",
-    "kyowa.hokkaido.jp",
-    "nagano.jp",
-    "masaki.ehime.jp",
-    "thd",
-    "juegos",
-    "rec.co",
-    "stjordalshalsen.no",
-    "kudamatsu.yamaguchi.jp",
-    "sebastopol.ua",
-    "photo",
-    "mincom.tn",
-    "wildlife.museum",
-    "kanonji.kagawa.jp",
-    "nsw.edu.au",
-    "minato.tokyo.jp",
-    "health.nz",
-    "com.al",
-    "family",
-    "com.ai",
-    "com.af",
-    "com.ag",
-    "tatarstan.ru",
-    "toei.aichi.jp",
-    "com.ac",
-    "blackfriday",
-    "com.az",
-    "com.aw",
-    "com.au",
-    "com.ar",
-    "viking",
-    "sumy.ua",
-    "ppg.br",
-    "hvaler.no",
-    "leksvik.no",
-    "\u{307f}\u{3093}\u{306a}",
-    "xbox",
-    "toda.saitama.jp",
-    "kep.tr",
-    "yasugi.shimane.jp",
-    "productions",
-    "cieszyn.pl",
-    "udono.mie.jp",
-    "ci.it",
-    "matsudo.chiba.jp",
-    "cancerresearch",
-    "tranoy.no",
-    "*.fj",
-    "*.fk",
-    "gotemba.shizuoka.jp",
-    "rissa.no",
-    "powiat.pl",
-    "marketing",
-    "asso.bj",
-    "laquila.it",
-    "lixil",
-    "post",
-    "saxo",
-    "properties",
-    "obi",
-    "astronomy.museum",
-    "akune.kagoshima.jp",
-    "edu.jo",
-    "hattfjelldal.no",
-    "plaza.museum",
-    "nowtv",
-    "xyz",
-    "beppu.oita.jp",
-    "\u{81fa}\u{7063}",
-    "gov.ua",
-    "jelenia-gora.pl",
-    "gov.uk",
-    "nakadomari.aomori.jp",
-    "vercelli.it",
-    "antiques.museum",
-    "kitagata.saga.jp",
-    "sakuho.nagano.jp",
-    "melhus.no",
-    "farmers.museum",
-    "higashikawa.hokkaido.jp",
-    "mk.ua",
-    "monza-brianza.it",
-    "kazuno.akita.jp",
-    "alsace",
-    "ustka.pl",
-    "\u{65b0}\u{6f5f}.jp",
-    "b\u{00e1}id\u{00e1}r.no",
-    "vads\u{00f8}.no",
-    "sch.lk",
-    "mtr",
-    "unazuki.toyama.jp",
-    "kibichuo.okayama.jp",
-    "mtn",
-    "sch.ly",
-    "tel.tr",
-    "ac.cy",
-    "ac.cr",
-    "ac.cn",
-    "ac.ci",
-    "lecce.it",
-    "izumiotsu.osaka.jp",
-    "takaharu.miyazaki.jp",
-    "shakotan.hokkaido.jp",
-    "kamimine.saga.jp",
-    "histoire.museum",
-    "moareke.no",
-    "lib.ee",
-    "nom.km",
-    "sor-odal.no",
-    "roma.it",
-    "weatherchannel",
-    "omega",
-    "stordal.no",
-    "anan.tokushima.jp",
-    "muni.il",
-    "gniezno.pl",
-    "pp.se",
-    "tsu.mie.jp",
-    "steiermark.museum",
-    "kanna.gunma.jp",
-    "dance",
-    "pro.ht",
-    "oslo.no",
-    "iwi.nz",
-    "otsuchi.iwate.jp",
-    "zone",
-    "\u{09ad}\u{09be}\u{09b0}\u{09a4}",
-    "nh.us",
-    "assassination.museum",
-    "og.it",
-    "zgrad.ru",
-    "uscountryestate.museum",
-    "net.ki",
-    "net.kn",
-    "izumozaki.niigata.jp",
-    "zp.ua",
-    "org.tr",
-    "net.kg",
-    "org.tt",
-    "net.kz",
-    "\u{7ec4}\u{7ec7}\u{673a}\u{6784}",
-    "net.ky",
-    "org.tn",
-    "org.to",
-    "org.tm",
-    "cc.ri.us",
-    "wa.gov.au",
-    "obama.nagasaki.jp",
-    "guitars",
-    "indian.museum",
-    "hida.gifu.jp",
-    "htc",
-    "kiho.mie.jp",
-    "or.jp",
-    "discover",
-    "hl.cn",
-    "detroit.museum",
-    "mitane.akita.jp",
-    "\u{0b87}\u{0bb2}\u{0b99}\u{0bcd}\u{0b95}\u{0bc8}",
-    "go.jp",
-    "higashiyodogawa.osaka.jp",
-    "hdfc",
-    "architecture.museum",
-    "vard\u{00f8}.no",
-    "tv.br",
-    "porsanger.no",
-    "i.ng",
-    "tv.bb",
-    "kikonai.hokkaido.jp",
-    "tv.bo",
-    "nj.us",
-    "nogata.fukuoka.jp",
-    "\u{043e}\u{043d}\u{043b}\u{0430}\u{0439}\u{043d}",
-    "l.bg",
-    "domains",
-    "vantaa.museum",
-    "med.ly",
-    "gob.mx",
-    "automotive.museum",
-    "yura.wakayama.jp",
-    "baghdad.museum",
-    "market",
-    "anthro.museum",
-    "tonami.toyama.jp",
-    "hekinan.aichi.jp",
-    "ojiya.niigata.jp",
-    "u.bg",
-    "live",
-    "ba.it",
-    "artsandcrafts.museum",
-    "club",
-    "lib.in.us",
-    "flekkefjord.no",
-    "isen.kagoshima.jp",
-    "massa-carrara.it",
-    "zarow.pl",
-    "ibm",
-    "car",
-    "fujixerox",
-    "cat",
-    "cc.nc.us",
-    "cal",
-    "cab",
-    "idrett.no",
-    "sm\u{00f8}la.no",
-    "matsukawa.nagano.jp",
-    "swidnica.pl",
-    "net.tt",
-    "hamar.no",
-    "nakhodka.ru",
-    "clothing",
-    "kurume.fukuoka.jp",
-    "asn.lv",
-    "spb.ru",
-    "kamogawa.chiba.jp",
-    "kawakami.nagano.jp",
-    "info.ve",
-    "info.vn",
-    "nagahama.shiga.jp",
-    "aol",
-    "fuoisku.no",
-    "web.do",
-    "jar.ru",
-    "higashiyama.kyoto.jp",
-    "aquarium.museum",
-    "iiyama.nagano.jp",
-    "ol.no",
-    "edu.ws",
-    "gd.cn",
-    "karate.museum",
-    "priv.hu",
-    "info.la",
-    "ieee",
-    "\u{5de5}\u{884c}",
-    "wv.us",
-    "flsmidth",
-    "net.tj",
-    "\u{96c6}\u{56e2}",
-    "est.pr",
-    "fnd.br",
-    "vi.it",
-    "aso.kumamoto.jp",
-    "medizinhistorisches.museum",
-    "r\u{00f8}st.no",
-    "chiba.jp",
-    "tjx",
-    "now",
-    "nome.pt",
-    "huissier-justice.fr",
-    "piacenza.it",
-    "minamiminowa.nagano.jp",
-    "tottori.jp",
-    "oceanographic.museum",
-    "salzburg.museum",
-    "moriya.ibaraki.jp",
-    "ee",
-    "eg",
-    "ec",
-    "soy",
-    "amsterdam",
-    "eu",
-    "et",
-    "ryazan.ru",
-    "es",
-    "com.ci",
-    "com.cm",
-    "com.cn",
-    "com.co",
-    "com.cy",
-    "trentino-s-tirol.it",
-    "com.cu",
-    "com.cw",
-    "sumita.iwate.jp",
-    "cars",
-    "school.nz",
-    "school.na",
-    "novara.it",
-    "kr.ua",
-    "mamurogawa.yamagata.jp",
-    "murayama.yamagata.jp",
-    "care",
-    "bern.museum",
-    "ks.us",
-    "shimamaki.hokkaido.jp",
-    "ks.ua",
-    "pamperedchef",
-    "gx.cn",
-    "odessa.ua",
-    "*.kobe.jp",
-    "pu.it",
-    "production.aero",
-    "shika.ishikawa.jp",
-    "tokamachi.niigata.jp",
-    "tsukigata.hokkaido.jp",
-    "lapy.pl",
-    "spreadbetting",
-    "katsuura.chiba.jp",
-    "pnc",
-    "flowers",
-    "yonaguni.okinawa.jp",
-    "narashino.chiba.jp",
-    "edu.ht",
-    "hsbc",
-    "edu.hn",
-    "edu.hk",
-    "hawaii.museum",
-    "hirokawa.fukuoka.jp",
-    "gs.bu.no",
-    "davvesiida.no",
-    "lib.mo.us",
-    "far.br",
-    "\u{062a}\u{0648}\u{0646}\u{0633}",
-    "bungoono.oita.jp",
-    "equipment",
-    "davvenj\u{00e1}rga.no",
-    "k12.al.us",
-    "online",
-    "fhv.se",
-    "sakaki.nagano.jp",
-    "oyamazaki.kyoto.jp",
-    "uslivinghistory.museum",
-    "yamagata.nagano.jp",
-    "teshikaga.hokkaido.jp",
-    "com.dm",
-    "lincoln.museum",
-    "sch.ng",
-    "afjord.no",
-    "com.do",
-    "yuzhno-sakhalinsk.ru",
-    "sue.fukuoka.jp",
-    "mifune.kumamoto.jp",
-    "ranzan.saitama.jp",
-    "szex.hu",
-    "fm.it",
-    "chofu.tokyo.jp",
-    "tsuwano.shimane.jp",
-    "sogne.no",
-    "koza.wakayama.jp",
-    "tsuru.yamanashi.jp",
-    "shirahama.wakayama.jp",
-    "bs.it",
-    "k12.co.us",
-    "nom.mg",
-    "consultant.aero",
-    "jamison.museum",
-    "catering",
-    "obuse.nagano.jp",
-    "yamato.kumamoto.jp",
-    "shirakawa.fukushima.jp",
-    "suzaka.nagano.jp",
-    "loten.no",
-    "fi.it",
-    "nanmoku.gunma.jp",
-    "org.ve",
-    "firestone",
-    "kariwa.niigata.jp",
-    "org.vc",
-    "suginami.tokyo.jp",
-    "org.vi",
-    "org.vu",
-    "harima.hyogo.jp",
-    "cc.vt.us",
-    "airline.aero",
-    "*.sendai.jp",
-    "net.iq",
-    "net.ir",
-    "net.is",
-    "style",
-    "nishiarita.saga.jp",
-    "miyakonojo.miyazaki.jp",
-    "net.id",
-    "onojo.fukuoka.jp",
-    "net.il",
-    "net.im",
-    "net.in",
-    "ac.ae",
-    "nov.ru",
-    "deals",
-    "ac.at",
-    "figueres.museum",
-    "moriguchi.osaka.jp",
-    "ama.shimane.jp",
-    "kitashiobara.fukushima.jp",
-    "fiat",
-    "kumenan.okayama.jp",
-    "ta.it",
-    "muenster.museum",
-    "minakami.gunma.jp",
-    "higashichichibu.saitama.jp",
-    "wien",
-    "miyama.fukuoka.jp",
-    "leg.br",
-    "cymru",
-    "shikama.miyagi.jp",
-    "katagami.akita.jp",
-    "hoyanger.no",
-    "nord-aurdal.no",
-    "aero",
-    "nikon",
-    "c.se",
-    "mulhouse.museum",
-    "hirara.okinawa.jp",
-    "western.museum",
-    "andasuolo.no",
-    "carrara-massa.it",
-    "\u{79fb}\u{52a8}",
-    "cc.ar.us",
-    "rwe",
-    "hanyu.saitama.jp",
-    "cc.as.us",
-    "ericsson",
-    "hiroo.hokkaido.jp",
-    "ichiba.tokushima.jp",
-    "narvik.no",
-    "plantation.museum",
-    "\u{05e7}\u{05d5}\u{05dd}",
-    "england.museum",
-    "scor",
-    "la.us",
-    "name.qa",
-    "otsuka",
-    "akabira.hokkaido.jp",
-    "www.ro",
-    "pulawy.pl",
-    "palmsprings.museum",
-    "brussel.museum",
-    "genoa.it",
-    "daiwa.hiroshima.jp",
-    "chiryu.aichi.jp",
-    "kashima.saga.jp",
-    "\u{0443}\u{043a}\u{0440}",
-    "info.tr",
-    "gov.nc.tr",
-    "info.tt",
-    "design.museum",
-    "kunisaki.oita.jp",
-    "info.tz",
-    "lyngen.no",
-    "hashima.gifu.jp",
-    "miyazu.kyoto.jp",
-    "info.tn",
-    "shimizu.hokkaido.jp",
-    "siljan.no",
-    "\u{9ad8}\u{77e5}.jp",
-    "lt.it",
-    "cg",
-    "mashiki.kumamoto.jp",
-    "mt.it",
-    "maebashi.gunma.jp",
-    "edu.ua",
-    "enterprises",
-    "balsan.it",
-    "edu.uy",
-    "gub.uy",
-    "technology.museum",
-    "cisco",
-    "apartments",
-    "spydeberg.no",
-    "hn.cn",
-    "sardegna.it",
-    "k12.az.us",
-    "nanao.ishikawa.jp",
-    "iheya.okinawa.jp",
-    "me.tz",
-    "trentinoalto-adige.it",
-    "hasami.nagasaki.jp",
-    "tdk",
-    "matsushige.tokushima.jp",
-    "epson",
-    "h\u{00f8}yanger.no",
-    "business",
-    "club.aero",
-    "volkswagen",
-    "kainan.wakayama.jp",
-    "saltdal.no",
-    "niigata.jp",
-    "otsu.shiga.jp",
-    "shimizu.shizuoka.jp",
-    "glade",
-    "miyake.nara.jp",
-    "selbu.no",
-    "gangwon.kr",
-    "aip.ee",
-    "toyonaka.osaka.jp",
-    "house.museum",
-    "on.ca",
-    "co.za",
-    "\u{77f3}\u{5ddd}.jp",
-    "pl.ua",
-    "daito.osaka.jp",
-    "umbria.it",
-    "com.es",
-    "fujisato.akita.jp",
-    "aerodrome.aero",
-    "com.et",
-    "yekaterinburg.ru",
-    "lol",
-    "holmestrand.no",
-    "com.ec",
-    "com.eg",
-    "com.ee",
-    "lombardia.it",
-    "te.it",
-    "\u{00e1}k\u{014b}oluokta.no",
-    "monticello.museum",
-    "roan.no",
-    "children.museum",
-    "luxembourg.museum",
-    "prochowice.pl",
-    "eti.br",
-    "yawara.ibaraki.jp",
-    "citadel",
-    "builders",
-    "futbol",
-    "shinichi.hiroshima.jp",
-    "s\u{00e1}l\u{00e1}t.no",
-    "sicilia.it",
-    "lundbeck",
-    "maserati",
-    "kutchan.hokkaido.jp",
-    "fukuyama.hiroshima.jp",
-    "stokke.no",
-    "misugi.mie.jp",
-    "storage",
-    "sakai.osaka.jp",
-    "bandai.fukushima.jp",
-    "ilawa.pl",
-    "k12.sc.us",
-    "nagara.chiba.jp",
-    "ayabe.kyoto.jp",
-    "asso.nc",
-    "al.it",
-    "indiana.museum",
-    "iitate.fukushima.jp",
-    "ueno.gunma.jp",
-    "genova.it",
-    "shisui.chiba.jp",
-    "kembuchi.hokkaido.jp",
-    "stryn.no",
-    "wif.gov.pl",
-    "kunigami.okinawa.jp",
-    "risor.no",
-    "biz.bb",
-    "southcarolina.museum",
-    "kounosu.saitama.jp",
-    "meraker.no",
-    "caseih",
-    "sa.cr",
-    "omotego.fukushima.jp",
-    "algard.no",
-    "safety",
-    "wme",
-    "dp.ua",
-    "tm.za",
-    "epilepsy.museum",
-    "dr.tr",
-    "sydney.museum",
-    "k12.vi",
-    "musashino.tokyo.jp",
-    "\u{7f51}\u{7ad9}",
-    "sevastopol.ua",
-    "presse.km",
-    "toyosato.shiga.jp",
-    "cr.ua",
-    "mizunami.gifu.jp",
-    "control.aero",
-    "muroto.kochi.jp",
-    "akrehamn.no",
-    "\u{5609}\u{91cc}\u{5927}\u{9152}\u{5e97}",
-    "suli.hu",
-    "cookingchannel",
-    "nc.tr",
-    "cbg.ru",
-    "kalmykia.su",
-    "aver\u{00f8}y.no",
-    "prd.fr",
-    "shimokawa.hokkaido.jp",
-    "museumvereniging.museum",
-    "jevnaker.no",
-    "tsukumi.oita.jp",
-    "misaki.osaka.jp",
-    "oryol.ru",
-    "syzran.ru",
-    "shari.hokkaido.jp",
-    "imperia.it",
-    "niki.hokkaido.jp",
-    "zagan.pl",
-    "savona.it",
-    "ny.us",
-    "va",
-    "toys",
-    "tra.kp",
-    "namegawa.saitama.jp",
-    "polkowice.pl",
-    "hayashima.okayama.jp",
-    "sv.it",
-    "uchihara.ibaraki.jp",
-    "shingo.aomori.jp",
-    "\u{5bcc}\u{5c71}.jp",
-    "berlev\u{00e5}g.no",
-    "udine.it",
-    "vao.it",
-    "net.gy",
-    "net.gt",
-    "net.gr",
-    "net.gp",
-    "net.gn",
-    "net.gl",
-    "ryuoh.shiga.jp",
-    "valle-d-aosta.it",
-    "net.gg",
-    "kesennuma.miyagi.jp",
-    "net.ge",
-    "gbiz",
-    "anquan",
-    "cc.ma.us",
-    "ac.gn",
-    "etc.br",
-    "vu",
-    "kuriyama.hokkaido.jp",
-    "kagoshima.kagoshima.jp",
-    "lib.or.us",
-    "yakumo.shimane.jp",
-    "york.museum",
-    "lib.al.us",
-    "sr.it",
-    "edu.sy",
-    "izumisano.osaka.jp",
-    "kiwi.nz",
-    "fr",
-    "odate.akita.jp",
-    "mitsuke.niigata.jp",
-    "bod\u{00f8}.no",
-    "fi",
-    "heguri.nara.jp",
-    "fm",
-    "fo",
-    "sayama.saitama.jp",
-    "montblanc",
-    "lezajsk.pl",
-    "fukuchi.fukuoka.jp",
-    "nagaoka.niigata.jp",
-    "ishikawa.jp",
-    "szkola.pl",
-    "circus.museum",
-    "help",
-    "bahn.museum",
-    "gloppen.no",
-    "icbc",
-    "mcdonalds",
-    "verm\u{00f6}gensberatung",
-    "akiruno.tokyo.jp",
-    "mansion.museum",
-    "systems",
-    "trentinoaadige.it",
-    "kure.hiroshima.jp",
-    "ceb",
-    "hembygdsforbund.museum",
-    "ceo",
-    "tamba.hyogo.jp",
-    "ninja",
-    "bodo.no",
-    "research.museum",
-    "yt",
-    "staples",
-    "tuva.ru",
-    "or.na",
-    "ichinomiya.chiba.jp",
-    "misato.akita.jp",
-    "wajima.ishikawa.jp",
-    "railway.museum",
-    "kuzumaki.iwate.jp",
-    "ifm",
-    "nakaniikawa.toyama.jp",
-    "educator.aero",
-    "fam.pk",
-    "cc.ga.us",
-    "numata.gunma.jp",
-    "engineer.aero",
-    "shingu.fukuoka.jp",
-    "mikasa.hokkaido.jp",
-    "hachioji.tokyo.jp",
-    "steinkjer.no",
-    "catania.it",
-    "info.ro",
-    "nasu.tochigi.jp",
-    "art.br",
-    "pub",
-    "arts.co",
-    "avellino.it",
-    "johana.toyama.jp",
-    "duns",
-    "k12.de.us",
-    "lier.no",
-    "agrinet.tn",
-    "norddal.no",
-    "edu.sl",
-    "academy.museum",
-    "edu.sn",
-    "edu.sa",
-    "edu.sc",
-    "edu.sb",
-    "edu.sd",
-    "edu.sg",
-    "shimofusa.chiba.jp",
-    "liaison",
-    "oto.fukuoka.jp",
-    "edu.st",
-    "edu.sv",
-    "cc.de.us",
-    "m\u{00e5}s\u{00f8}y.no",
-    "\u{00e5}mot.no",
-    "horse",
-    "*.sch.uk",
-    "khabarovsk.ru",
-    "k12.ok.us",
-    "flor\u{00f8}.no",
-    "stc",
-    "tokashiki.okinawa.jp",
-    "hanno.saitama.jp",
-    "kindle",
-    "dtv",
-    "cmw.ru",
-    "chikusei.ibaraki.jp",
-    "sk.ca",
-    "salat.no",
-    "aosta-valley.it",
-    "telekommunikation.museum",
-    "biz.vn",
-    "aknoluokta.no",
-    "com.gy",
-    "zama.kanagawa.jp",
-    "\u{7f51}\u{5740}",
-    "com.gt",
-    "com.gp",
-    "com.gr",
-    "snoasa.no",
-    "com.gl",
-    "nannestad.no",
-    "com.gn",
-    "com.gh",
-    "com.gi",
-    "com.ge",
-    "g.bg",
-    "oe.yamagata.jp",
-    "vang.no",
-    "hikimi.shimane.jp",
-    "zachpomor.pl",
-    "agric.za",
-    "fuossko.no",
-    "mobily",
-    "komagane.nagano.jp",
-    "bzh",
-    "notaires.km",
-    "kuki.saitama.jp",
-    "lucca.it",
-    "ulsan.kr",
-    "nsk.ru",
-    "conf.lv",
-    "matsuda.kanagawa.jp",
-    "kameyama.mie.jp",
-    "nishiazai.shiga.jp",
-    "gunma.jp",
-    "tokyo.jp",
-    "flights",
-    "circle",
-    "kuji.iwate.jp",
-    "alfaromeo",
-    "asso.ht",
-    "nishi.osaka.jp",
-    "\u{6803}\u{6728}.jp",
-    "l\u{00f8}ten.no",
-    "\u{7ec4}\u{7e54}.hk",
-    "net.sb",
-    "res.in",
-    "komi.ru",
-    "niimi.okayama.jp",
-    "\u{7f51}\u{7edc}",
-    "arendal.no",
-    "kemerovo.ru",
-    "ris\u{00f8}r.no",
-    "settsu.osaka.jp",
-    "hagi.yamaguchi.jp",
-    "uz.ua",
-    "joetsu.niigata.jp",
-    "emr.it",
-    "bykle.no",
-    "pc.it",
-    "lib.nd.us",
-    "ashiya.hyogo.jp",
-    "\u{0570}\u{0561}\u{0575}",
-    "\u{673a}\u{6784}",
-    "sa.au",
-    "wow",
-    "teva",
-    "pr.us",
-    "\u{9910}\u{5385}",
-    "4.bg",
-    "rn.it",
-    "sakawa.kochi.jp",
-    "piedmont.it",
-    "makeup",
-    "sfr",
-    "fin.tn",
-    "owariasahi.aichi.jp",
-    "biratori.hokkaido.jp",
-    "agro.pl",
-    "presse.ml",
-    "wanouchi.gifu.jp",
-    "cc.pa.us",
-    "imakane.hokkaido.jp",
-    "sch.jo",
-    "misato.miyagi.jp",
-    "eisenbahn.museum",
-    "rost.no",
-    "shibuya.tokyo.jp",
-    "sd.us",
-    "\u{4fe1}\u{606f}",
-    "bievat.no",
-    "inf.cu",
-    "ferrero",
-    "ostrowwlkp.pl",
-    "nom.ag",
-    "naha.okinawa.jp",
-    "gs.svalbard.no",
-    "kiyose.tokyo.jp",
-    "salerno.it",
-    "kiyama.saga.jp",
-    "kasama.ibaraki.jp",
-    "design.aero",
-    "naples.it",
-    "macerata.it",
-    "k12.me.us",
-    "grajewo.pl",
-    "akita.jp",
-    "yamatsuri.fukushima.jp",
-    "langevag.no",
-    "oxford.museum",
-    "cc.gu.us",
-    "caltanissetta.it",
-    "guide",
-    "ina.nagano.jp",
-    "hapmir.no",
-    "kids.us",
-    "skype",
-    "shaw",
-    "org.za",
-    "urausu.hokkaido.jp",
-    "ivanovo.su",
-    "miyazaki.miyazaki.jp",
-    "microlight.aero",
-    "gol.no",
-    "net.et",
-    "haus",
-    "gobo.wakayama.jp",
-    "net.ec",
-    "zoological.museum",
-    "net.eg",
-    "mobi",
-    "rokunohe.aomori.jp",
-    "naoshima.kagawa.jp",
-    "marker.no",
-    "usa.oita.jp",
-    "desi",
-    "go.pw",
-    "toyooka.hyogo.jp",
-    "museum.mv",
-    "oshima.tokyo.jp",
-    "lunner.no",
-    "koshu.yamanashi.jp",
-    "toscana.it",
-    "tako.chiba.jp",
-    "jefferson.museum",
-    "takikawa.hokkaido.jp",
-    "irkutsk.ru",
-    "vestnes.no",
-    "lubin.pl",
-    "dubai",
-    "game",
-    "web.pk",
-    "leikanger.no",
-    "floro.no",
-    "itoigawa.niigata.jp",
-    "larsson.museum",
-    "toyota",
-    "hitachi.ibaraki.jp",
-    "himeji.hyogo.jp",
-    "science-fiction.museum",
-    "undersea.museum",
-    "\u{0639}\u{0645}\u{0627}\u{0646}",
-    "rade.no",
-    "sandiego.museum",
-    "versailles.museum",
-    "togura.nagano.jp",
-    "kagoshima.jp",
-    "shibata.niigata.jp",
-    "costume.museum",
-    "koeln",
-    "veterinaire.fr",
-    "jprs",
-    "run",
-    "force.museum",
-    "kursk.ru",
-    "iide.yamagata.jp",
-    "oguni.yamagata.jp",
-    "kurotaki.nara.jp",
-    "sanofi",
-    "faith",
-    "plants.museum",
-    "nd.us",
-    "chikuma.nagano.jp",
-    "cody.museum",
-    "sejny.pl",
-    "mobi.gp",
-    "chattanooga.museum",
-    "kitadaito.okinawa.jp",
-    "nb.ca",
-    "info.pl",
-    "info.pk",
-    "krasnodar.su",
-    "\u{5609}\u{91cc}",
-    "info.pr",
-    "stalowa-wola.pl",
-    "fortmissoula.museum",
-    "rl.no",
-    "takamori.kumamoto.jp",
-    "mn.us",
-    "esurance",
-    "ragusa.it",
-    "agr.br",
-    "noto.ishikawa.jp",
-    "art.dz",
-    "mt.us",
-    "hgtv",
-    "koori.fukushima.jp",
-    "pwc",
-    "art.do",
-    "no.it",
-    "j\u{00f8}lster.no",
-    "info",
-    "shiiba.miyazaki.jp",
-    "shimada.shizuoka.jp",
-    "edu.qa",
-    "politie",
-    "gob.gt",
-    "jessheim.no",
-    "kawanishi.yamagata.jp",
-    "americana.museum",
-    "sande.vestfold.no",
-    "sn\u{00e5}sa.no",
-    "yodobashi",
-    "makurazaki.kagoshima.jp",
-    "nab",
-    "fetsund.no",
-    "doshi.yamanashi.jp",
-    "abarth",
-    "firm.nf",
-    "fr\u{00e6}na.no",
-    "lplfinancial",
-    "yokawa.hyogo.jp",
-    "\u{5546}\u{696d}.tw",
-    "stcgroup",
-    "yamanakako.yamanashi.jp",
-    "games.hu",
-    "aquila.it",
-    "missoula.museum",
-    "youtube",
-    "mil.eg",
-    "k12.pa.us",
-    "mil.ec",
-    "starachowice.pl",
-    "gs.st.no",
-    "gw",
-    "\u{5175}\u{5eab}.jp",
-    "soeda.fukuoka.jp",
-    "mordovia.ru",
-    "ap.gov.pl",
-    "gr",
-    "gq",
-    "gp",
-    "vladikavkaz.su",
-    "gy",
-    "nanto.toyama.jp",
-    "gf",
-    "ge",
-    "gd",
-    "gb",
-    "ga",
-    "lib.wy.us",
-    "gn",
-    "oshu.iwate.jp",
-    "gl",
-    "\u{0627}\u{06cc}\u{0631}\u{0627}\u{0646}.ir",
-    "gi",
-    "gh",
-    "\u{4f5b}\u{5c71}",
-    "assedic.fr",
-    "lib.nh.us",
-    "\u{0643}\u{0648}\u{0645}",
-    "oyodo.nara.jp",
-    "kddi",
-    "tsuyama.okayama.jp",
-    "contemporaryart.museum",
-    "repbody.aero",
-    "\u{03b5}\u{03bb}",
-    "izumi.kagoshima.jp",
-    "kr\u{00f8}dsherad.no",
-    "tozawa.yamagata.jp",
-    "mod.gi",
-    "kota.aichi.jp",
-    "malopolska.pl",
-    "tomari.hokkaido.jp",
-    "\u{0440}\u{0444}",
-    "boots",
-    "ln.cn",
-    "vestby.no",
-    "download",
-    "oji.nara.jp",
-    "sapo",
-    "xz.cn",
-    "\u{30dd}\u{30a4}\u{30f3}\u{30c8}",
-    "*.np",
-    "warszawa.pl",
-    "adygeya.su",
-    "\u{0643}\u{0627}\u{062b}\u{0648}\u{0644}\u{064a}\u{0643}",
-    "rzeszow.pl",
-    "lib.nj.us",
-    "usculture.museum",
-    "ullensvang.no",
-    "airport.aero",
-    "gent",
-    "ono.fukui.jp",
-    "k12.nh.us",
-    "oystre-slidre.no",
-    "tmall",
-    "yamagata.yamagata.jp",
-    "win",
-    "yoshida.saitama.jp",
-    "takamatsu.kagawa.jp",
-    "gos.pk",
-    "moskenes.no",
-    "cloud",
-    "commbank",
-    "jgora.pl",
-    "kumiyama.kyoto.jp",
-    "press.aero",
-    "camera",
-    "gulen.no",
-    "tm.fr",
-    "yachiyo.chiba.jp",
-    "solund.no",
-    "shiriuchi.hokkaido.jp",
-    "caserta.it",
-    "\u{0627}\u{0644}\u{0633}\u{0639}\u{0648}\u{062f}\u{06cc}\u{0629}",
-    "bz.it",
-    "meet",
-    "cc.ne.us",
-    "sund.no",
-    "n.bg",
-    "woodside",
-    "hotmail",
-    "us.gov.pl",
-    "\u{0627}\u{0644}\u{0633}\u{0639}\u{0648}\u{062f}\u{06cc}\u{06c3}",
-    "bialystok.pl",
-    "ryokami.saitama.jp",
-    "cz.it",
-    "pink",
-    "loppa.no",
-    "creation.museum",
-    "lib.sc.us",
-    "biei.hokkaido.jp",
-    "aland.fi",
-    "monmouth.museum",
-    "mus.br",
-    "donna.no",
-    "simbirsk.ru",
-    "nord-odal.no",
-    "kamaishi.iwate.jp",
-    "ogawara.miyagi.jp",
-    "jl.cn",
-    "v.bg",
-    "ntr.br",
-    "scjohnson",
-    "healthcare",
-    "taxi.br",
-    "com.ly",
-    "ping",
-    "otaki.saitama.jp",
-    "onomichi.hiroshima.jp",
-    "cruise",
-    "jnj",
-    "fuefuki.yamanashi.jp",
-    "net.ci",
-    "net.cn",
-    "net.co",
-    "suwa.nagano.jp",
-    "net.cm",
-    "net.cw",
-    "net.cu",
-    "agano.niigata.jp",
-    "wodzislaw.pl",
-    "net.cy",
-    "com.lr",
-    "ab.ca",
-    "auto",
-    "kira.aichi.jp",
-    "bbva",
-    "rishiri.hokkaido.jp",
-    "himi.toyama.jp",
-    "beeldengeluid.museum",
-    "nyc",
-    "motobu.okinawa.jp",
-    "fusa.no",
-    "university",
-    "moda",
-    "toya.hokkaido.jp",
-    "katori.chiba.jp",
-    "verona.it",
-    "d.se",
-    "globo",
-    "yamagata.gifu.jp",
-    "lidl",
-    "ishinomaki.miyagi.jp",
-    "bedzin.pl",
-    "saiki.oita.jp",
-    "media.museum",
-    "sandoy.no",
-    "averoy.no",
-    "czeladz.pl",
-    "manchester.museum",
-    "krokstadelva.no",
-    "giehtavuoatna.no",
-    "spot",
-    "tsaritsyn.ru",
-    "gouv.bj",
-    "calvinklein",
-    "higashimatsushima.miyagi.jp",
-    "!city.sapporo.jp",
-    "kumano.mie.jp",
-    "wada.nagano.jp",
-    "sugito.saitama.jp",
-    "baltimore.museum",
-    "czest.pl",
-    "!www.ck",
-    "kamigori.hyogo.jp",
-    "nantan.kyoto.jp",
-    "id.ly",
-    "id.lv",
-    "tx.us",
-    "pescara.it",
-    "ikawa.akita.jp",
-    "tsushima.aichi.jp",
-    "art.museum",
-    "final",
-    "nesset.no",
-    "pictet",
-    "kvinnherad.no",
-    "yoshida.shizuoka.jp",
-    "insurance.aero",
-    "fukui.fukui.jp",
-    "sandnes.no",
-    "ngo.za",
-    "kg",
-    "lamborghini",
-    "friuli-vgiulia.it",
-    "bibai.hokkaido.jp",
-    "urakawa.hokkaido.jp",
-    "whaling.museum",
-    "or.bi",
-    "bet",
-    "sobetsu.hokkaido.jp",
-    "tadotsu.kagawa.jp",
-    "edeka",
-    "buy",
-    "belluno.it",
-    "jur.pro",
-    "kuromatsunai.hokkaido.jp",
-    "sj",
-    "able",
-    "amli.no",
-    "mango",
-    "vt.it",
-    "reggio-calabria.it",
-    "localhistory.museum",
-    "penza.ru",
-    "aws",
-    "matta-varjjat.no",
-    "hiphop",
-    "sciencecenters.museum",
-    "gallery",
-    "kitahata.saga.jp",
-    "delivery",
-    "gob.es",
-    "nt.edu.au",
-    "sunagawa.hokkaido.jp",
-    "nakagawa.fukuoka.jp",
-    "jx.cn",
-    "geometre-expert.fr",
-    "gob.ec",
-    "pictures",
-    "ne.pw",
-    "karasjok.no",
-    "tokuyama.yamaguchi.jp",
-    "edu.py",
-    "kani.gifu.jp",
-    "sanuki.kagawa.jp",
-    "arao.kumamoto.jp",
-    "miyagi.jp",
-    "partners",
-    "artcenter.museum",
-    "forex",
-    "e.se",
-    "nesna.no",
-    "ngo",
-    "chichibu.saitama.jp",
-    "cremona.it",
-    "dodge",
-    "\u{611b}\u{77e5}.jp",
-    "iwakura.aichi.jp",
-    "wajiki.tokushima.jp",
-    "flanders.museum",
-    "trentinosued-tirol.it",
-    "muos\u{00e1}t.no",
-    "potenza.it",
-    "rieti.it",
-    "sch.qa",
-    "mil.gt",
-    "sagae.yamagata.jp",
-    "mil.gh",
-    "tunes",
-    "mil.ge",
-    "tank.museum",
-    "uenohara.yamanashi.jp",
-    "\u{30b9}\u{30c8}\u{30a2}",
-    "km",
-    "computer",
-    "brussels.museum",
-    "mr.no",
-    "r\u{00e1}isa.no",
-    "\u{00f8}vre-eiker.no",
-    "org.kn",
-    "skj\u{00e5}k.no",
-    "pokrovsk.su",
-    "farm.museum",
-    "unzen.nagasaki.jp",
-    "flickr",
-    "and\u{00f8}y.no",
-    "utazas.hu",
-    "padova.it",
-    "labor.museum",
-    "samegawa.fukushima.jp",
-    "group",
-    "konin.pl",
-    "wtc",
-    "museum.om",
-    "columbus.museum",
-    "oishida.yamagata.jp",
-    "arna.no",
-    "gr.it",
-    "fredrikstad.no",
-    "obira.hokkaido.jp",
-    "oi.kanagawa.jp",
-    "dudinka.ru",
-    "org.ae",
-    "org.ag",
-    "palace.museum",
-    "pin",
-    "tagawa.fukuoka.jp",
-    "org.al",
-    "nakanojo.gunma.jp",
-    "org.ai",
-    "org.au",
-    "org.ar",
-    "h\u{00e1}bmer.no",
-    "org.az",
-    "wtf",
-    "shiroi.chiba.jp",
-    "mo.cn",
-    "childrensgarden.museum",
-    "ha.cn",
-    "hamburg",
-    "trading.aero",
-    "ott",
-    "gucci",
-    "moma.museum",
-    "aguni.okinawa.jp",
-    "dali.museum",
-    "*.ck",
-    "jeju.kr",
-    "tomi.nagano.jp",
-    "pubol.museum",
-    "dlugoleka.pl",
-    "finance",
-    "pr.it",
-    "homesense",
-    "lajolla.museum",
-    "parts",
-    "fido",
-    "idf.il",
-    "party",
-    "tateyama.chiba.jp",
-    "hurdal.no",
-    "hjartdal.no",
-    "buzz",
-    "valer.ostfold.no",
-    "erotika.hu",
-    "sm.ua",
-    "abashiri.hokkaido.jp",
-    "oygarden.no",
-    "metlife",
-    "yuasa.wakayama.jp",
-    "cc.sd.us",
-    "shinyoshitomi.fukuoka.jp",
-    "philadelphiaarea.museum",
-    "fukaya.saitama.jp",
-    "\u{0938}\u{0902}\u{0917}\u{0920}\u{0928}",
-    "audnedaln.no",
-    "fujinomiya.shizuoka.jp",
-    "de.us",
-    "yugawa.fukushima.jp",
-    "act.au",
-    "nom.es",
-    "pol.dz",
-    "nagawa.nagano.jp",
-    "pa.us",
-    "higashiizumo.shimane.jp",
-    "gifu.jp",
-    "ushuaia.museum",
-    "otsuki.yamanashi.jp",
-    "kannami.shizuoka.jp",
-    "gov.cu",
-    "lincoln",
-    "foggia.it",
-    "kasukabe.saitama.jp",
-    "gov.cy",
-    "gov.cx",
-    "gov.cd",
-    "gov.cm",
-    "gov.cl",
-    "gov.co",
-    "bajddar.no",
-    "page",
-    "inf.br",
-    "shichinohe.aomori.jp",
-    "*.kitakyushu.jp",
-    "grozny.ru",
-    "jlc",
-    "jll",
-    "net.al",
-    "romsa.no",
-    "kaizuka.osaka.jp",
-    "net.af",
-    "net.ag",
-    "net.ac",
-    "net.az",
-    "net.au",
-    "net.ar",
-    "pro.br",
-    "shimodate.ibaraki.jp",
-    "anan.nagano.jp",
-    "miasa.nagano.jp",
-    "yaizu.shizuoka.jp",
-    "bjugn.no",
-    "uozu.toyama.jp",
-    "minamisanriku.miyagi.jp",
-    "lib.va.us",
-    "american.museum",
-    "yamada.toyama.jp",
-    "kamikoani.akita.jp",
-    "tottori.tottori.jp",
-    "rodeo",
-    "nom.ad",
-    "gangaviika.no",
-    "av.tr",
-    "ringerike.no",
-    "ca.na",
-    "arts.museum",
-    "miyazaki.jp",
-    "skien.no",
-    "go.th",
-    "amursk.ru",
-    "szczecin.pl",
-    "museum",
-    "museum.mw",
-    "accountant",
-    "chuo.chiba.jp",
-    "suzuki",
-    "sera.hiroshima.jp",
-    "iki.nagasaki.jp",
-    "cricket",
-    "hr",
-    "nyny.museum",
-    "iveland.no",
-    "xerox",
-    "ht",
-    "hu",
-    "hk",
-    "chizu.tottori.jp",
-    "hn",
-    "mil.do",
-    "hk.cn",
-    "eat",
-    "bushey.museum",
-    "yabuki.fukushima.jp",
-    "hosting",
-    "bialowieza.pl",
-    "lipsy",
-    "shinonsen.hyogo.jp",
-    "warmia.pl",
-    "handa.aichi.jp",
-    "beats",
-    "soja.okayama.jp",
-    "education",
-    "kunstsammlung.museum",
-    "kimobetsu.hokkaido.jp",
-    "eiheiji.fukui.jp",
-    "star",
-    "living",
-    "gs.of.no",
-    "express.aero",
-    "bcn",
-    "samsung",
-    "bcg",
-    "yazu.tottori.jp",
-    "hitra.no",
-    "mugi.tokushima.jp",
-    "mzansimagic",
-    "naustdal.no",
-    "toyako.hokkaido.jp",
-    "takarazuka.hyogo.jp",
-    "seiro.niigata.jp",
-    "com.vn",
-    "hyuga.miyazaki.jp",
-    "art.ht",
-    "podlasie.pl",
-    "friulivegiulia.it",
-    "ando.nara.jp",
-    "kakuda.miyagi.jp",
-    "workshop.museum",
-    "v\u{00e6}r\u{00f8}y.no",
-    "org.gh",
-    "erimo.hokkaido.jp",
-    "olecko.pl",
-    "americanantiques.museum",
-    "mutsuzawa.chiba.jp",
-    "hamburg.museum",
-    "sagamihara.kanagawa.jp",
-    "fineart.museum",
-    "finnoy.no",
-    "gob.cl",
-    "karasjohka.no",
-    "chikushino.fukuoka.jp",
-    "nishikawa.yamagata.jp",
-    "freiburg.museum",
-    "chonan.chiba.jp",
-    "abogado",
-    "community.museum",
-    "nec",
-    "asia",
-    "parma.it",
-    "youth.museum",
-    "net",
-    "farmstead.museum",
-    "usarts.museum",
-    "izumizaki.fukushima.jp",
-    "meo",
-    "men",
-    "nakagyo.kyoto.jp",
-    "and.museum",
-    "nagasaki.jp",
-    "carrier.museum",
-    "st.no",
-    "otake.hiroshima.jp",
-    "active",
-    "yk.ca",
-    "motoyama.kochi.jp",
-    "mil.az",
-    "iwama.ibaraki.jp",
-    "mil.ar",
-    "r.se",
-    "credit",
-    "sch.sa",
-    "mil.al",
-    "chrysler",
-    "mil.ac",
-    "mil.ae",
-    "city.hu",
-    "shinanomachi.nagano.jp",
-    "alto-adige.it",
-    "passenger-association.aero",
-    "agriculture.museum",
-    "pa.gov.pl",
-    "realestate.pl",
-    "re.it",
-    "murakami.niigata.jp",
-    "ing.pa",
-    "kadoma.osaka.jp",
-    "army",
-    "ic.gov.pl",
-    "yokoshibahikari.chiba.jp",
-    "media.aero",
-    "lib.ky.us",
-    "miura.kanagawa.jp",
-    "eastafrica.museum",
-    "z.se",
-    "ss.it",
-    "gs.hm.no",
-    "cc.me.us",
-    "berlin",
-    "adult",
-    "stjordal.no",
-    "room",
-    "tanagura.fukushima.jp",
-    "vodka",
-    "taketomi.okinawa.jp",
-    "ogata.akita.jp",
-    "karelia.su",
-    "tynset.no",
-    "bi.it",
-    "muenchen.museum",
-    "piaget",
-    "org.co",
-    "org.cn",
-    "resistance.museum",
-    "\u{4e2d}\u{4fe1}",
-    "ed.pw",
-    "org.ci",
-    "iwaizumi.iwate.jp",
-    "ct.us",
-    "ichikawa.chiba.jp",
-    "\u{5927}\u{62ff}",
-    "org.cy",
-    "org.cw",
-    "org.cu",
-    "asuke.aichi.jp",
-    "koriyama.fukushima.jp",
-    "yusuhara.kochi.jp",
-    "stpetersburg.museum",
-    "flatanger.no",
-    "ovh",
-    "aoste.it",
-    "m\u{0101}ori.nz",
-    "riodejaneiro.museum",
-    "gliding.aero",
-    "cv.ua",
-    "co.bb",
-    "co.ba",
-    "snasa.no",
-    "gs.ah.no",
-    "co.bi",
-    "co.bw",
-    "rv.ua",
-    "koshigaya.saitama.jp",
-    "pug.it",
-    "kongsvinger.no",
-    "vinnytsia.ua",
-    "shiwa.iwate.jp",
-    "togo.aichi.jp",
-    "steam.museum",
-    "association.museum",
-    "nemuro.hokkaido.jp",
-    "tolga.no",
-    "askoy.no",
-    "nachikatsuura.wakayama.jp",
-    "toyota.aichi.jp",
-    "b\u{00e1}l\u{00e1}t.no",
-    "fujimino.saitama.jp",
-    "presse.ci",
-    "fe.it",
-    "bolt.hu",
-    "eidskog.no",
-    "sakae.nagano.jp",
-    "urawa.saitama.jp",
-    "vladimir.ru",
-    "udm.ru",
-    "asmatart.museum",
-    "oumu.hokkaido.jp",
-    "minami-alps.yamanashi.jp",
-    "mikawa.yamagata.jp",
-    "bond",
-    "u.se",
-    "nakatombetsu.hokkaido.jp",
-    "trading",
-    "hatogaya.saitama.jp",
-    "navy",
-    "tw.cn",
-    "nord-fron.no",
-    "saito.miyazaki.jp",
-    "bauhaus",
-    "\u{010d}\u{00e1}hcesuolo.no",
-    "hm",
-    "online.museum",
-    "furniture.museum",
-    "yasu.shiga.jp",
-    "iris.arpa",
-    "salangen.no",
-    "\u{4eac}\u{90fd}.jp",
-    "onagawa.miyagi.jp",
-    "katowice.pl",
-    "gov.az",
-    "gov.au",
-    "gov.as",
-    "gov.ar",
-    "ist",
-    "gov.al",
-    "okaya.nagano.jp",
-    "gov.af",
-    "gov.ae",
-    "gov.ac",
-    "cleaning",
-    "contractors",
-    "arida.wakayama.jp",
-    "sciencehistory.museum",
-    "my.id",
-    "cc.dc.us",
-    "brussels",
-    "sassari.it",
-    "bieszczady.pl",
-    "takazaki.miyazaki.jp",
-    "direct",
-    "bel.tr",
-    "svalbard.no",
-    "savannahga.museum",
-    "mattel",
-    "realestate",
-    "kawahara.tottori.jp",
-    "takayama.gunma.jp",
-    "verdal.no",
-    "yachts",
-    "mail.pl",
-    "mint",
-    "gjovik.no",
-    "lancome",
-    "gouv.fr",
-    "ricoh",
-    "i.bg",
-    "friuli-ve-giulia.it",
-    "murmansk.ru",
-    "od.ua",
-    "tv.na",
-    "hitachiota.ibaraki.jp",
-    "gushikami.okinawa.jp",
-    "indianapolis.museum",
-    "marine.ru",
-    "t\u{00f8}nsberg.no",
-    "tananger.no",
-    "fundacio.museum",
-    "meme",
-    "cargo.aero",
-    "uji.kyoto.jp",
-    "7.bg",
-    "fi.cr",
-    "khmelnytskyi.ua",
-    "takamori.nagano.jp",
-    "kusatsu.gunma.jp",
-    "francaise.museum",
-    "lancia",
-    "chernivtsi.ua",
-    "dot",
-    "tenkawa.nara.jp",
-    "illustration.museum",
-    "koenig.ru",
-    "suzuka.mie.jp",
-    "chitose.hokkaido.jp",
-    "com.py",
-    "com.pt",
-    "arpa",
-    "com.ps",
-    "com.pr",
-    "com.pl",
-    "historical.museum",
-    "com.ph",
-    "com.pk",
-    "com.pe",
-    "com.pf",
-    "com.pa",
-    "dstv",
-    "kuokgroup",
-    "bar",
-    "abr.it",
-    "\u{00e5}l.no",
-    "playstation",
-    "msk.su",
-    "khakassia.ru",
-    "fuji.shizuoka.jp",
-    "kamikawa.saitama.jp",
-    "kanagawa.jp",
-    "taira.toyama.jp",
-    "lancaster",
-    "voyage",
-    "vt.us",
-    "ogimi.okinawa.jp",
-    "shibukawa.gunma.jp",
-    "capetown",
-    "harvestcelebration.museum",
-    "taka.hyogo.jp",
-    "tozsde.hu",
-    "nysa.pl",
-    "bungotakada.oita.jp",
-    "toyotomi.hokkaido.jp",
-    "prudential",
-    "ne.tz",
-    "gob.ar",
-    "\u{00e5}s.no",
-    "soc.lk",
-    "tochio.niigata.jp",
-    "uno",
-    "lib.wa.us",
-    "hobol.no",
-    "kayabe.hokkaido.jp",
-    "ikaruga.nara.jp",
-    "ami.ibaraki.jp",
-    "firm.ht",
-    "county.museum",
-    "parliament.nz",
-    "k12.ia.us",
-    "mragowo.pl",
-    "temasek",
-    "fg.it",
-    "website",
-    "mcd",
-    "mil.co",
-    "mil.cn",
-    "mil.cl",
-    "k12.vi.us",
-    "koryo.nara.jp",
-    "pisz.pl",
-    "vn.ua",
-    "uppo.gov.pl",
-    "osteroy.no",
-    "ventures",
-    "lib.ks.us",
-    "iz.hr",
-    "seven",
-    "kamishihoro.hokkaido.jp",
-    "doha",
-    "iq",
-    "is",
-    "ir",
-    "it",
-    "gaular.no",
-    "katsushika.tokyo.jp",
-    "im",
-    "il",
-    "io",
-    "in",
-    "batsfjord.no",
-    "ie",
-    "id",
-    "\u{5546}\u{57ce}",
-    "sap",
-    "mie.jp",
-    "naturbruksgymn.se",
-    "siellak.no",
-    "cuneo.it",
-    "bloomberg",
-    "fr.it",
-    "bc.ca",
-    "kia",
-    "lib.hi.us",
-    "trader.aero",
-    "gen.in",
-    "claims",
-    "ulvik.no",
-    "investments",
-    "wakayama.wakayama.jp",
-    "\u{5927}\u{962a}.jp",
-    "\u{00e5}lesund.no",
-    "fujisawa.iwate.jp",
-    "prato.it",
-    "weber",
-    "secure",
-    "\u{8bfa}\u{57fa}\u{4e9a}",
-    "k12.dc.us",
-    "matsubushi.saitama.jp",
-    "org.ee",
-    "org.eg",
-    "state.museum",
-    "its.me",
-    "statebank",
-    "org.es",
-    "save",
-    "org.et",
-    "dovre.no",
-    "divttasvuotna.no",
-    "shirataka.yamagata.jp",
-    "akita.akita.jp",
-    "lillesand.no",
-    "kiyosu.aichi.jp",
-    "co.lc",
-    "govt.nz",
-    "uchinomi.kagawa.jp",
-    "name.mk",
-    "medio-campidano.it",
-    "\u{9999}\u{683c}\u{91cc}\u{62c9}",
-    "org.qa",
-    "niiza.saitama.jp",
-    "co.ls",
-    "sa.it",
-    "ah.cn",
-    "\u{7db2}\u{8def}.tw",
-    "deal",
-    "inagi.tokyo.jp",
-    "ryugasaki.ibaraki.jp",
-    "asahi.toyama.jp",
-    "cityeats",
-    "gov.ee",
-    "tv.sd",
-    "\u{0aad}\u{0abe}\u{0ab0}\u{0aa4}",
-    "dr.na",
-    "taiji.wakayama.jp",
-    "louvre.museum",
-    "l.se",
-    "services.aero",
-    "kyotamba.kyoto.jp",
-    "berlevag.no",
-    "itoman.okinawa.jp",
-    "lecco.it",
-    "azure",
-    "isehara.kanagawa.jp",
-    "tachikawa.tokyo.jp",
-    "minokamo.gifu.jp",
-    "odesa.ua",
-    "americanart.museum",
-    "sorum.no",
-    "aeroport.fr",
-    "!city.kitakyushu.jp",
-    "per.nf",
-    "viajes",
-    "abeno.osaka.jp",
-    "tennis",
-    "kharkov.ua",
-    "ostroleka.pl",
-    "!city.sendai.jp",
-    "togane.chiba.jp",
-    "settlement.museum",
-    "ford",
-    "katsuyama.fukui.jp",
-    "cc.ia.us",
-    "scienceandhistory.museum",
-    "askvoll.no",
-    "gov.gy",
-    "rawa-maz.pl",
-    "gov.gr",
-    "gov.gi",
-    "gov.gh",
-    "gov.gn",
-    "zara",
-    "windmill.museum",
-    "ota.tokyo.jp",
-    "gov.ge",
-    "warman",
-    "bharti",
-    "volyn.ua",
-    "shia",
-    "akashi.hyogo.jp",
-    "drive",
-    "mobi.na",
-    "mobi.ng",
-    "tokai.ibaraki.jp",
-    "vista",
-    "vossevangen.no",
-    "research.aero",
-    "lib.as.us",
-    "honjo.akita.jp",
-    "digital",
-    "historyofscience.museum",
-    "friulivenezia-giulia.it",
-    "sling",
-    "diet",
-    "kui.hiroshima.jp",
-    "losangeles.museum",
-    "ind.br",
-    "gs.nt.no",
-    "ne.kr",
-    "perm.ru",
-    "paragliding.aero",
-    "takko.aomori.jp",
-    "news.hu",
-    "tomigusuku.okinawa.jp",
-    "tienda",
-    "skin",
-    "kuwana.mie.jp",
-    "yachimata.chiba.jp",
-    "cc.mn.us",
-    "hirogawa.wakayama.jp",
-    "gouv.ht",
-    "mihama.aichi.jp",
-    "construction",
-    "mg",
-    "info.ec",
-    "kameoka.kyoto.jp",
-    "yalta.ua",
-    "mc",
-    "coop.tt",
-    "democrat",
-    "abu.yamaguchi.jp",
-    "diy",
-    "seljord.no",
-    "com.re",
-    "com.ro",
-    "show.aero",
-    "com.rw",
-    "qh.cn",
-    "com.ru",
-    "kimino.wakayama.jp",
-    "oseto.nagasaki.jp",
-    "ainan.ehime.jp",
-    "noboribetsu.hokkaido.jp",
-    "yatsushiro.kumamoto.jp",
-    "p.bg",
-    "ascoli-piceno.it",
-    "higashiagatsuma.gunma.jp",
-    "tingvoll.no",
-    "mv",
-    "vindafjord.no",
-    "international",
-    "fussa.tokyo.jp",
-    "guardian",
-    "alesund.no",
-    "okinawa",
-    "ca.it",
-    "tomiya.miyagi.jp",
-    "seaport.museum",
-    "bugatti",
-    "box",
-    "hbo",
-    "biella.it",
-    "institute",
-    "bot",
-    "bom",
-    "n\u{00f8}tter\u{00f8}y.no",
-    "boo",
-    "taipei",
-    "yonabaru.okinawa.jp",
-    "yatomi.aichi.jp",
-    "environment.museum",
-    "v\u{00e5}gan.no",
-    "bayern",
-    "leirfjord.no",
-    "lewismiller.museum",
-    "kazimierz-dolny.pl",
-    "*.ye",
-    "po.gov.pl",
-    "ooo",
-    "kunimi.fukushima.jp",
-    "bygland.no",
-    "shingu.hyogo.jp",
-    "realty",
-    "fly",
-    "tokyo",
-    "reviews",
-    "kisosaki.mie.jp",
-    "ookuwa.nagano.jp",
-    "sowa.ibaraki.jp",
-    "shimoda.shizuoka.jp",
-    "firm.ve",
-    "monash",
-    "saigawa.fukuoka.jp",
-    "kochi.kochi.jp",
-    "kumagaya.saitama.jp",
-    "aizuwakamatsu.fukushima.jp",
-    "trentino-alto-adige.it",
-    "orsta.no",
-    "mil.mg",
-    "rikubetsu.hokkaido.jp",
-    "mil.my",
-    "mil.mv",
-    "pistoia.it",
-    "nagano.nagano.jp",
-    "man",
-    "iwanuma.miyagi.jp",
-    "x.bg",
-    "hioki.kagoshima.jp",
-    "talk",
-    "izu.shizuoka.jp",
-    "shoes",
-    "morioka.iwate.jp",
-    "valle.no",
-    "shibata.miyagi.jp",
-    "jamal.ru",
-    "air-surveillance.aero",
-    "okinoshima.shimane.jp",
-    "lib.ak.us",
-    "maif",
-    "asaminami.hiroshima.jp",
-    "js.cn",
-    "yoshinogari.saga.jp",
-    "shinagawa.tokyo.jp",
-    "pn.it",
-    "brumunddal.no",
-    "voronezh.ru",
-    "ws.na",
-    "coloradoplateau.museum",
-    "lib.ct.us",
-    "togliatti.su",
-    "bus.museum",
-    "shunan.yamaguchi.jp",
-    "noda.chiba.jp",
-    "haram.no",
-    "aizubange.fukushima.jp",
-    "aizumi.tokushima.jp",
-    "shirako.chiba.jp",
-    "org.gr",
-    "org.gp",
-    "sabae.fukui.jp",
-    "bari.it",
-    "org.gt",
-    "org.gy",
-    "krager\u{00f8}.no",
-    "iwanai.hokkaido.jp",
-    "org.gg",
-    "org.ge",
-    "org.gi",
-    "vc.it",
-    "org.gn",
-    "org.gl",
-    "monster",
-    "cuisinella",
-    "ishikari.hokkaido.jp",
-    "nozawaonsen.nagano.jp",
-    "toyota.yamaguchi.jp",
-    "malvik.no",
-    "org",
-    "gotv",
-    "co.ni",
-    "kisofukushima.nagano.jp",
-    "co.na",
-    "zushi.kanagawa.jp",
-    "isleofman.museum",
-    "co.nz",
-    "kommunalforbund.se",
-    "kashiwara.osaka.jp",
-    "forsale",
-    "fudai.iwate.jp",
-    "wiki",
-    "obu.aichi.jp",
-    "tver.ru",
-    "\u{0627}\u{064a}\u{0631}\u{0627}\u{0646}",
-    "saroma.hokkaido.jp",
-    "ritto.shiga.jp",
-    "kumakogen.ehime.jp",
-    "\u{10d2}\u{10d4}",
-    "tm.no",
-    "ud.it",
-    "chicago.museum",
-    "fujieda.shizuoka.jp",
-    "rana.no",
-    "tinn.no",
-    "moscow",
-    "\u{043e}\u{0440}\u{0433}.\u{0441}\u{0440}\u{0431}",
-    "cr.it",
-    "kristiansund.no",
-    "naganohara.gunma.jp",
-    "co",
-    "sf.no",
-    "alstom",
-    "khv.ru",
-    "asago.hyogo.jp",
-    "etisalat",
-    "wy.us",
-    "historicalsociety.museum",
-    "bokn.no",
-    "panama.museum",
-    "tobe.ehime.jp",
-    "lib.nc.us",
-    "kawanishi.nara.jp",
-    "amfam",
-    "hasama.oita.jp",
-    "jaworzno.pl",
-    "gemological.museum",
-    "law.pro",
-    "konan.shiga.jp",
-    "channel",
-    "gov.ec",
-    "gov.eg",
-    "donostia.museum",
-    "shell.museum",
-    "meiwa.mie.jp",
-    "gov.et",
-    "\u{0b9a}\u{0bbf}\u{0b99}\u{0bcd}\u{0b95}\u{0baa}\u{0bcd}\u{0baa}\u{0bc2}\u{0bb0}\u{0bcd}",
-    "biz.ni",
-    "itakura.gunma.jp",
-    "hamaroy.no",
-    "capebreton.museum",
-    "ino.kochi.jp",
-    "biz.nr",
-    "encyclopedic.museum",
-    "klabu.no",
-    "horten.no",
-    "show",
-    "bozen.it",
-    "takahama.fukui.jp",
-    "\u{4e2d}\u{6587}\u{7f51}",
-    "sk\u{00e1}nit.no",
-    "info.et",
-    "livorno.it",
-    "iwate.jp",
-    "art.sn",
-    "black",
-    "ac.se",
-    "sakurai.nara.jp",
-    "shikatsu.aichi.jp",
-    "gea",
-    "ac.sz",
-    "museumcenter.museum",
-    "versicherung",
-    "lukow.pl",
-    "per.la",
-    "pup.gov.pl",
-    "atm.pl",
-    "\u{0639}\u{0631}\u{0627}\u{0642}",
-    "yorii.saitama.jp",
-    "l\u{00e4}ns.museum",
-    "seat",
-    "cologne",
-    "ibaraki.ibaraki.jp",
-    "torino.museum",
-    "medecin.km",
-    "diamonds",
-    "herad.no",
-    "ontario.museum",
-    "\u{5546}\u{5e97}",
-    "olkusz.pl",
-    "jp",
-    "tui",
-    "jo",
-    "moriyoshi.akita.jp",
-    "je",
-    "med.br",
-    "coupons",
-    "\u{7ec4}\u{7ec7}.hk",
-    "chungbuk.kr",
-    "koebenhavn.museum",
-    "novosibirsk.ru",
-    "ralingen.no",
-    "ens.tn",
-    "bas.it",
-    "g.se",
-    "com.tj",
-    "com.tm",
-    "com.to",
-    "com.tn",
-    "com.tr",
-    "com.tt",
-    "aostavalley.it",
-    "ladbrokes",
-    "arte",
-    "military.museum",
-    "fujioka.gunma.jp",
-    "snaase.no",
-    "m\u{00e5}lselv.no",
-    "nosegawa.nara.jp",
-    "bms",
-    "bo.it",
-    "enebakk.no",
-    "bmw",
-    "grong.no",
-    "austrheim.no",
-    "fedex",
-    "or.it",
-    "aichi.jp",
-    "chuo.yamanashi.jp",
-    "to.it",
-    "inderoy.no",
-    "cc.vi.us",
-    "pay",
-    "\u{0a2d}\u{0a3e}\u{0a30}\u{0a24}",
-    "nore-og-uvdal.no",
-    "matsumae.hokkaido.jp",
-    "miyako.iwate.jp",
-    "edu.gt",
-    "edu.gp",
-    "edu.gr",
-    "edu.gy",
-    "edu.ge",
-    "berg.no",
-    "edu.gl",
-    "mar.it",
-    "fukui.jp",
-    "edu.gh",
-    "kosei.shiga.jp",
-    "higashiyoshino.nara.jp",
-    "okinawa.jp",
-    "cs.it",
-    "\u{0647}\u{0645}\u{0631}\u{0627}\u{0647}",
-    "bill.museum",
-    "katsuragi.wakayama.jp",
-    "tokke.no",
-    "bjarkoy.no",
-    "money",
-    "bergamo.it",
-    "yuzawa.niigata.jp",
-    "tarnobrzeg.pl",
-    "bn.it",
-    "mutsu.aomori.jp",
-    "nahari.kochi.jp",
-    "minowa.nagano.jp",
-    "sado.niigata.jp",
-    "karm\u{00f8}y.no",
-    "friuliv-giulia.it",
-    "miyama.mie.jp",
-    "yuza.yamagata.jp",
-    "careers",
-    "sarl",
-    "sakae.chiba.jp",
-    "otaru.hokkaido.jp",
-    "ginowan.okinawa.jp",
-    "mov",
-    "spjelkavik.no",
-    "moi",
-    "mom",
-    "guovdageaidnu.no",
-    "moe",
-    "beardu.no",
-    "gaivuotna.no",
-    "iwakuni.yamaguchi.jp",
-    "tahara.aichi.jp",
-    "nishihara.kumamoto.jp",
-    "air-traffic-control.aero",
-    "tur.br",
-    "rovno.ua",
-    "com.tw",
-    "ujitawara.kyoto.jp",
-    "castres.museum",
-    "telecity",
-    "lotte",
-    "giessen.museum",
-    "ryukyu",
-    "v\u{00e5}ler.\u{00f8}stfold.no",
-    "yakumo.hokkaido.jp",
-    "lotto",
-    "kr\u{00e5}anghke.no",
-    "takahata.yamagata.jp",
-    "pioneer",
-    "strand.no",
-    "homedepot",
-    "nom.pe",
-    "nom.pa",
-    "goto.nagasaki.jp",
-    "kyoto",
-    "fitness",
-    "broker",
-    "qld.edu.au",
-    "oum.gov.pl",
-    "\u{7d44}\u{7e54}.hk",
-    "k\u{00e1}r\u{00e1}\u{0161}johka.no",
-    "tara.saga.jp",
-    "keisen.fukuoka.jp",
-    "res.aero",
-    "marriott",
-    "izunokuni.shizuoka.jp",
-    "coupon",
-    "hachijo.tokyo.jp",
-    "tajimi.gifu.jp",
-    "ouchi.saga.jp",
-    "kosa.kumamoto.jp",
-    "org.iq",
-    "org.is",
-    "org.ir",
-    "org.im",
-    "org.il",
-    "org.in",
-    "cincinnati.museum",
-    "namie.fukushima.jp",
-    "wskr.gov.pl",
-    "iselect",
-    "andria-trani-barletta.it",
-    "ujiie.tochigi.jp",
-    "tamatsukuri.ibaraki.jp",
-    "langev\u{00e5}g.no",
-    "co.hu",
-    "fylkesbibl.no",
-    "tsukuba.ibaraki.jp",
-    "\u{7f51}\u{7edc}.hk",
-    "fla.no",
-    "axis.museum",
-    "shimonita.gunma.jp",
-    "cc.nv.us",
-    "nu.it",
-    "nl.no",
-    "grainger",
-    "lib.vt.us",
-    "onjuku.chiba.jp",
-    "sr.gov.pl",
-    "film",
-    "soccer",
-    "va.it",
-    "istanbul",
-    "nt.au",
-    "finn\u{00f8}y.no",
-    "ashibetsu.hokkaido.jp",
-    "creditcard",
-    "lib.md.us",
-    "gjerdrum.no",
-    "int.tt",
-    "fst.br",
-    "barreau.bj",
-    "halsa.no",
-    "suldal.no",
-    "shinshiro.aichi.jp",
-    "network",
-    "\u{0645}\u{0648}\u{0628}\u{0627}\u{064a}\u{0644}\u{064a}",
-    "bananarepublic",
-    "nativeamerican.museum",
-    "andebu.no",
-    "aramco",
-    "tr\u{00e6}na.no",
-    "gt",
-    "gs",
-    "kosuge.yamanashi.jp",
-    "game.tw",
-    "k12.wi.us",
-    "chesapeakebay.museum",
-    "\u{516c}\u{53f8}.cn",
-    "komono.mie.jp",
-    "marche.it",
-    "coal.museum",
-    "gov.km",
-    "gov.kn",
-    "gov.ki",
-    "gov.kg",
-    "maryland.museum",
-    "ostroda.pl",
-    "gov.ky",
-    "gov.kz",
-    "aisho.shiga.jp",
-    "oster\u{00f8}y.no",
-    "gov.kp",
-    "gg",
-    "hakusan.ishikawa.jp",
-    "minamiuonuma.niigata.jp",
-    "stor-elvdal.no",
-    "asahi.yamagata.jp",
-    "biz.pl",
-    "name.ng",
-    "name.na",
-    "biz.pk",
-    "kanuma.tochigi.jp",
-    "info.co",
-    "ambulance.aero",
-    "hitachi",
-    "zero",
-    "quebec",
-    "kuju.oita.jp",
-    "gm",
-    "shizukuishi.iwate.jp",
-    "abc",
-    "kanan.osaka.jp",
-    "hakata.fukuoka.jp",
-    "web.id",
-    "lel.br",
-    "kinko.kagoshima.jp",
-    "iwafune.tochigi.jp",
-    "modelling.aero",
-    "vic.edu.au",
-    "journal.aero",
-    "jor.br",
-    "satosho.okayama.jp",
-    "ringebu.no",
-    "bible",
-    "trogstad.no",
-    "barlettatraniandria.it",
-    "tenri.nara.jp",
-    "mihama.fukui.jp",
-    "gob.ve",
-    "cpa.pro",
-    "!city.yokohama.jp",
-    "civilwar.museum",
-    "television.museum",
-    "caravan",
-    "heroy.nordland.no",
-    "annaka.gunma.jp",
-    "funagata.yamagata.jp",
-    "kawanehon.shizuoka.jp",
-    "sosnowiec.pl",
-    "minamiise.mie.jp",
-    "*.kawasaki.jp",
-    "oarai.ibaraki.jp",
-    "lib.mt.us",
-    "cc.hi.us",
-    "aikawa.kanagawa.jp",
-    "hirono.iwate.jp",
-    "mb.ca",
-    "mil.to",
-    "mil.tm",
-    "mil.tj",
-    "asakuchi.okayama.jp",
-    "mil.tw",
-    "g\u{00e1}ivuotna.no",
-    "mil.tr",
-    "itau",
-    "sko.gov.pl",
-    "mil.tz",
-    "town",
-    "higashiyamato.tokyo.jp",
-    "sucks",
-    "dev",
-    "hannan.osaka.jp",
-    "wiki.br",
-    "\u{73e0}\u{5b9d}",
-    "avocat.pro",
-    "bergbau.museum",
-    "ayase.kanagawa.jp",
-    "com.vi",
-    "shinjo.nara.jp",
-    "agakhan",
-    "mansions.museum",
-    "com.vc",
-    "com.ve",
-    "dn.ua",
-    "firmdale",
-    "dabur",
-    "shimamoto.osaka.jp",
-    "com.vu",
-    "audible",
-    "family.museum",
-    "bydgoszcz.pl",
-    "csc",
-    "uzhgorod.ua",
-    "sukagawa.fukushima.jp",
-    "stjohn.museum",
-    "hokksund.no",
-    "torino.it",
-    "alibaba",
-    "targi.pl",
-    "frog.museum",
-    "hakuba.nagano.jp",
-    "volda.no",
-    "kaufen",
-    "tatsuno.nagano.jp",
-    "samara.ru",
-    "tourism.tn",
-    "\u{5c71}\u{68a8}.jp",
-    "obihiro.hokkaido.jp",
-    "kariya.aichi.jp",
-    "higashi.fukushima.jp",
-    "midtre-gauldal.no",
-    "lund.no",
-    "yn.cn",
-    "emiliaromagna.it",
-    "goog",
-    "roros.no",
-    "edu.et",
-    "edu.es",
-    "historichouses.museum",
-    "hellas.museum",
-    "edu.eg",
-    "edu.ee",
-    "edu.ec",
-    "wa.au",
-    "co.ug",
-    "cagliari.it",
-    "aki.kochi.jp",
-    "botanicgarden.museum",
-    "co.uk",
-    "mj\u{00f8}ndalen.no",
-    "ap.it",
-    "co.uz",
-    "joburg",
-    "house",
-    "energy",
-    "r\u{00f8}mskog.no",
-    "kanmaki.nara.jp",
-    "higashikagawa.kagawa.jp",
-    "firm.ro",
-    "\u{00f8}rskog.no",
-    "americanexpress",
-    "jewishart.museum",
-    "dni.us",
-    "adult.ht",
-    "store.ve",
-    "ballangen.no",
-    "nsn.us",
-    "is.gov.pl",
-    "taketa.oita.jp",
-    "mil.iq",
-    "sbs",
-    "\u{5c90}\u{961c}.jp",
-    "abudhabi",
-    "wolterskluwer",
-    "mil.id",
-    "beauxarts.museum",
-    "sbi",
-    "1.bg",
-    "mil.in",
-    "okinawa.okinawa.jp",
-    "rebun.hokkaido.jp",
-    "uchinada.ishikawa.jp",
-    "cosenza.it",
-    "mn.it",
-    "takahagi.ibaraki.jp",
-    "top",
-    "kakegawa.shizuoka.jp",
-    "baseball.museum",
-    "tatar",
-    "valleeaoste.it",
-    "hoylandet.no",
-    "a.se",
-    "aure.no",
-    "sondre-land.no",
-    "edu.gn",
-    "kinder",
-    "edu.gi",
-    "waw.pl",
-    "education.museum",
-    "tychy.pl",
-    "nom.ro",
-    "nom.re",
-    "lease",
-    "mini",
-    "gratangen.no",
-    "\u{00f8}ygarden.no",
-    "n\u{00e6}r\u{00f8}y.no",
-    "wa.us",
-    "bytom.pl",
-    "skole.museum",
-    "hammarfeasta.no",
-    "tsubetsu.hokkaido.jp",
-    "cfa",
-    "berkeley.museum",
-    "\u{901a}\u{8ca9}",
-    "sochi.su",
-    "gosen.niigata.jp",
-    "forlicesena.it",
-    "toyotsu.fukuoka.jp",
-    "zlg.br",
-    "trysil.no",
-    "org.kg",
-    "takayama.nagano.jp",
-    "at.it",
-    "org.km",
-    "org.ki",
-    "seki.gifu.jp",
-    "namdalseid.no",
-    "org.kp",
-    "takaishi.osaka.jp",
-    "assn.lk",
-    "org.kz",
-    "org.ky",
-    "blanco",
-    "friulive-giulia.it",
-    "mo.it",
-    "build",
-    "e12.ve",
-    "lavangen.no",
-    "dunlop",
-    "achi.nagano.jp",
-    "d.bg",
-    "wlocl.pl",
-    "ac.jp",
-    "services",
-    "osaki.miyagi.jp",
-    "travel.pl",
-    "tas.edu.au",
-    "kamiichi.toyama.jp",
-    "ki",
-    "kn",
-    "babia-gora.pl",
-    "co.jp",
-    "kr",
-    "kp",
-    "in.rs",
-    "kz",
-    "co.je",
-    "sosa.chiba.jp",
-    "museet.museum",
-    "miyoshi.saitama.jp",
-    "\u{79cb}\u{7530}.jp",
-    "b.br",
-    "sogndal.no",
-    "budejju.no",
-    "frei.no",
-    "gouv.sn",
-    "yorkshire.museum",
-    "int.ve",
-    "nt.ca",
-    "setagaya.tokyo.jp",
-    "arakawa.tokyo.jp",
-    "express",
-    "nanporo.hokkaido.jp",
-    "bofa",
-    "lom.no",
-    "silk",
-    "motorcycles",
-    "tirol",
-    "chintai",
-    "uruma.okinawa.jp",
-    "\u{611b}\u{5a9b}.jp",
-    "takahashi.okayama.jp",
-    "xfinity",
-    "ferrara.it",
-    "minamiyamashiro.kyoto.jp",
-    "expert",
-    "fans",
-    "gov.it",
-    "gov.is",
-    "gov.ir",
-    "gov.iq",
-    "accident-investigation.aero",
-    "press.museum",
-    "gov.ie",
-    "hkt",
-    "gov.in",
-    "gov.il",
-    "lind\u{00e5}s.no",
-    "slg.br",
-    "restaurant",
-    "nishinoomote.kagoshima.jp",
-    "aukra.no",
-    "oceanographique.museum",
-    "aid.pl",
-    "prof.pr",
-    "info.au",
-    "info.az",
-    "chita.aichi.jp",
-    "stavern.no",
-    "ebetsu.hokkaido.jp",
-    "finearts.museum",
-    "rikuzentakata.iwate.jp",
-    "iwate.iwate.jp",
-    "net.ws",
-    "shizuoka.shizuoka.jp",
-    "miyota.nagano.jp",
-    "orenburg.ru",
-    "whoswho",
-    "med.sd",
-    "med.sa",
-    "sport.hu",
-    "vestre-slidre.no",
-    "iyo.ehime.jp",
-    "\u{70b9}\u{770b}",
-    "gap",
-    "vana",
-    "gal",
-    "kopervik.no",
-    "jorpeland.no",
-    "xihuan",
-    "kusatsu.shiga.jp",
-    "convent.museum",
-    "sar.it",
-    "hanggliding.aero",
-    "fun",
-    "bryansk.ru",
-    "unjarga.no",
-    "wakkanai.hokkaido.jp",
-    "viterbo.it",
-    "hasuda.saitama.jp",
-    "shinjo.yamagata.jp",
-    "hidaka.wakayama.jp",
-    "\u{00f8}ksnes.no",
-    "vanylven.no",
-    "\u{548c}\u{6b4c}\u{5c71}.jp",
-    "utsira.no",
-    "ia.us",
-    "hole.no",
-    "mil.vc",
-    "arita.saga.jp",
-    "mil.ve",
-    "yanaizu.fukushima.jp",
-    "saitama.jp",
-    "taki.mie.jp",
-    "atsugi.kanagawa.jp",
-    "sld.pa",
-    "\u{516b}\u{5366}",
-    "\u{043a}\u{0430}\u{0442}\u{043e}\u{043b}\u{0438}\u{043a}",
-    "swiss",
-    "s\u{00f8}rreisa.no",
-    "mc.it",
-    "com.ht",
-    "com.hr",
-    "lds",
-    "hamamatsu.shizuoka.jp",
-    "com.hn",
-    "alstahaug.no",
-    "com.hk",
-    "apple",
-    "tjmaxx",
-    "tainai.niigata.jp",
-    "tr.it",
-    "cim.br",
-    "f\u{00f8}rde.no",
-    "bio",
-    "tkmaxx",
-    "tamano.okayama.jp",
-    "ginoza.okinawa.jp",
-    "bid",
-    "shiojiri.nagano.jp",
-    "hi.us",
-    "fedje.no",
-    "bale.museum",
-    "biz",
-    "taranto.it",
-    "fujiyoshida.yamanashi.jp",
-    "gose.nara.jp",
-    "miyoshi.aichi.jp",
-    "onyourside",
-    "dagestan.ru",
-    "br\u{00f8}nn\u{00f8}y.no",
-    "ohi.fukui.jp",
-    "volkenkunde.museum",
-    "okayama.jp",
-    "google",
-    "belgorod.ru",
-    "trentino-aadige.it",
-    "org.rs",
-    "org.ru",
-    "b\u{00f8}.nordland.no",
-    "recreation.aero",
-    "jewelry",
-    "nishinoshima.shimane.jp",
-    "pet",
-    "bamble.no",
-    "vlaanderen",
-    "fhsk.se",
-    "kaluga.ru",
-    "communication.museum",
-    "of.no",
-    "presidio.museum",
-    "umi.fukuoka.jp",
-    "santabarbara.museum",
-    "edu.cu",
-    "edu.cw",
-    "maritime.museum",
-    "edu.ci",
-    "edu.co",
-    "edu.cn",
-    "pfizer",
-    "kiryu.gunma.jp",
-    "eu.int",
-    "\u{0634}\u{0628}\u{0643}\u{0629}",
-    "\u{b2f7}\u{cef4}",
-    "vicenza.it",
-    "oga.akita.jp",
-    "florida.museum",
-    "linz.museum",
-    "yakutia.ru",
-    "uchiko.ehime.jp",
-    "tajiri.osaka.jp",
-    "odawara.kanagawa.jp",
-    "rad\u{00f8}y.no",
-    "field.museum",
-    "lugansk.ua",
-    "int.mv",
-    "int.mw",
-    "hamatama.saga.jp",
-    "zgorzelec.pl",
-    "wi.us",
-    "ako.hyogo.jp",
-    "fuchu.toyama.jp",
-    "pacific.museum",
-    "mil.kz",
-    "trentinosudtirol.it",
-    "mil.kr",
-    "higashisumiyoshi.osaka.jp",
-    "depot.museum",
-    "shiroishi.saga.jp",
-    "kijo.miyazaki.jp",
-    "government.aero",
-    "mil.kg",
-    "oskol.ru",
-    "lancashire.museum",
-    "sc.tz",
-    "lib.ri.us",
-    "\u{7b87}\u{4eba}.hk",
-    "helsinki.museum",
-    "os.hordaland.no",
-    "yaotsu.gifu.jp",
-    "security",
-    "kodaira.tokyo.jp",
-    "o.bg",
-    "nom.tm",
-    "livinghistory.museum",
-    "prd.km",
-    "int.bo",
-    "cafe",
-    "school.za",
-    "tarui.gifu.jp",
-    "turen.tn",
-    "sennan.osaka.jp",
-    "kustanai.ru",
-    "k.bg",
-    "tsurugashima.saitama.jp",
-    "hayakawa.yamanashi.jp",
-    "jeonbuk.kr",
-    "oita.jp",
-    "toyoake.aichi.jp",
-    "laspezia.it",
-    "gov.rw",
-    "gov.ru",
-    "hiratsuka.kanagawa.jp",
-    "gov.rs",
-    "\u{6e38}\u{620f}",
-    "funahashi.toyama.jp",
-    "kinokawa.wakayama.jp",
-    "k12.or.us",
-    "wielun.pl",
-    "kawazu.shizuoka.jp",
-    "bashkiria.ru",
-    "h\u{00f8}nefoss.no",
-    "dds",
-    "yokohama",
-    "namikata.ehime.jp",
-    "statefarm",
-    "lindas.no",
-    "vi.us",
-    "org.ma",
-    "org.me",
-    "org.mg",
-    "pomorze.pl",
-    "amber.museum",
-    "org.mk",
-    "org.ml",
-    "org.mo",
-    "org.mn",
-    "org.ms",
-    "org.mu",
-    "org.mt",
-    "org.mw",
-    "org.mv",
-    "org.my",
-    "org.mx",
-    "mitsubishi",
-    "haibara.shizuoka.jp",
-    "nom.pl",
-    "davvenjarga.no",
-    "gs.tr.no",
-    "weather",
-    "lilly",
-    "evje-og-hornnes.no",
-    "hichiso.gifu.jp",
-    "traniandriabarletta.it",
-    "tono.iwate.jp",
-    "mino.gifu.jp",
-    "wassamu.hokkaido.jp",
-    "childrens.museum",
-    "org.ac",
-    "taku.saga.jp",
-    "mel\u{00f8}y.no",
-    "civilaviation.aero",
-    "fukudomi.saga.jp",
-    "surgeonshall.museum",
-    "goodyear",
-    "hanamaki.iwate.jp",
-    "naruto.tokushima.jp",
-    "ide.kyoto.jp",
-    "uki.kumamoto.jp",
-    "shijonawate.osaka.jp",
-    "sex.pl",
-    "int.pt",
-    "giving",
-    "tm.hu",
-    "nieruchomosci.pl",
-    "\u{4e2d}\u{56fd}",
-    "\u{96fb}\u{8a0a}\u{76c8}\u{79d1}",
-    "h\u{00e6}gebostad.no",
-    "exchange",
-    "gs.ol.no",
-    "mazury.pl",
-    "b\u{00f8}mlo.no",
-    "shinto.gunma.jp",
-    "sauda.no",
-    "chikuhoku.nagano.jp",
-    "sina",
-    "lib.ny.us",
-    "schweiz.museum",
-    "cern",
-    "hirono.fukushima.jp",
-    "koto.shiga.jp",
-    "kvinesdal.no",
-    "stada",
-    "pramerica",
-    "bauern.museum",
-    "pol.ht",
-    "touch.museum",
-    "k12.wy.us",
-    "limo",
-    "amusement.aero",
-    "yamaguchi.jp",
-    "fukuchiyama.kyoto.jp",
-    "valer.hedmark.no",
-    "kitayama.wakayama.jp",
-    "trentino-sued-tirol.it",
-    "mimata.miyazaki.jp",
-    "tsuno.kochi.jp",
-    "shioya.tochigi.jp",
-    "trentino-suedtirol.it",
-    "bradesco",
-    "or.ug",
-    "kim",
-    "\u{4e2d}\u{570b}",
-    "name.jo",
-    "or.us",
-    "settlers.museum",
-    "stranda.no",
-    "gov.om",
-    "yokosuka.kanagawa.jp",
-    "go.ci",
-    "kikugawa.shizuoka.jp",
-    "baikal.ru",
-    "cc.wi.us",
-    "biz.tr",
-    "biz.tt",
-    "biz.tj",
-    "aremark.no",
-    "broker.aero",
-    "go.cr",
-    "sortland.no",
-    "nalchik.su",
-    "\u{0e04}\u{0e2d}\u{0e21}",
-    "net.uk",
-    "tab",
-    "i.ph",
-    "chigasaki.kanagawa.jp",
-    "net.ua",
-    "sango.nara.jp",
-    "net.uy",
-    "net.uz",
-    "afl",
-    "kongsberg.no",
-    "loyalist.museum",
-    "shimokitayama.nara.jp",
-    "cc.mi.us",
-    "\u{653f}\u{5e9c}.hk",
-    "goshiki.hyogo.jp",
-    "valleaosta.it",
-    "gc.ca",
-    "physio",
-    "ac.uk",
-    "trieste.it",
-    "ac.ug",
-    "collection.museum",
-    "bing",
-    "chihayaakasaka.osaka.jp",
-    "walbrzych.pl",
-    "marshalls",
-    "\u{9577}\u{5d0e}.jp",
-    "leka.no",
-    "wazuka.kyoto.jp",
-    "sk\u{00e5}nland.no",
-    "industries",
-    "lb",
-    "lc",
-    "la",
-    "bryne.no",
-    "lk",
-    "li",
-    "lv",
-    "lt",
-    "lu",
-    "lr",
-    "ls",
-    "ozora.hokkaido.jp",
-    "ly",
-    "dad",
-    "mil.ph",
-    "tr\u{00f8}gstad.no",
-    "mil.pl",
-    "yamazoe.nara.jp",
-    "piemonte.it",
-    "mil.pe",
-    "nom.co",
-    "mil.py",
-    "sweden.museum",
-    "day",
-    "shimane.shimane.jp",
-    "travel.tt",
-    "project.museum",
-    "kumamoto.kumamoto.jp",
-    "gallo",
-    "cc.il.us",
-    "kagawa.jp",
-    "garden.museum",
-    "r.bg",
-    "\u{795e}\u{5948}\u{5ddd}.jp",
-    "aurskog-h\u{00f8}land.no",
-    "z.bg",
-    "com.jo",
-    "trd.br",
-    "aga.niigata.jp",
-    "*.yokohama.jp",
-    "masfjorden.no",
-    "sncf",
-    "farm",
-    "ren",
-    "donetsk.ua",
-    "arq.br",
-    "shimotsuke.tochigi.jp",
-    "red",
-    "tourism.pl",
-    "radom.pl",
-    "computerhistory.museum",
-    "susaki.kochi.jp",
-    "crew.aero",
-    "w.se",
-    "narusawa.yamanashi.jp",
-    "emp.br",
-    "nakatsugawa.gifu.jp",
-    "recipes",
-    "hokkaido.jp",
-    "h\u{00e1}pmir.no",
-    "nishiwaki.hyogo.jp",
-    "time.no",
-    "ed.ao",
-    "ar.us",
-    "skiptvet.no",
-    "kraanghke.no",
-    "loab\u{00e1}t.no",
-    "edu.ac",
-    "kaszuby.pl",
-    "k.se",
-    "edu.af",
-    "gsm.pl",
-    "lib.ca.us",
-    "kirov.ru",
-    "edu.al",
-    "edu.ar",
-    "edu.au",
-    "mihara.hiroshima.jp",
-    "as.us",
-    "bosch",
-    "skedsmo.no",
-    "k12.in.us",
-    "\u{5ca9}\u{624b}.jp",
-    "yanagawa.fukuoka.jp",
-    "spiegel",
-    "nkz.ru",
-    "vennesla.no",
-    "s\u{00f8}r-odal.no",
-    "omihachiman.shiga.jp",
-    "k12.ri.us",
-    "paderborn.museum",
-    "o.se",
-    "kamo.niigata.jp",
-    "newhampshire.museum",
-    "airforce",
-    "skoczow.pl",
-    "wanggou",
-    "cc.na",
-    "tatsuno.hyogo.jp",
-    "mil",
-    "journalism.museum",
-    "store.ro",
-    "tm.se",
-    "arezzo.it",
-    "lib.nm.us",
-    "sciencesnaturelles.museum",
-    "mit",
-    "zhytomyr.ua",
-    "balsfjord.no",
-    "kms.ru",
-    "kitakata.fukushima.jp",
-    "yaita.tochigi.jp",
-    "midatlantic.museum",
-    "tula.ru",
-    "vagan.no",
-    "ipiranga",
-    "cc.in.us",
-    "po.it",
-    "shimotsuma.ibaraki.jp",
-    "suita.osaka.jp",
-    "fhs.no",
-    "asnes.no",
-    "te.ua",
-    "\u{b2f7}\u{b137}",
-    "freight.aero",
-    "chiropractic.museum",
-    "haboro.hokkaido.jp",
-    "prd.mg",
-    "evenes.no",
-    "realtor",
-    "muncie.museum",
-    "yoichi.hokkaido.jp",
-    "nankoku.kochi.jp",
-    "usui.fukuoka.jp",
-    "gov.ph",
-    "photography",
-    "gov.pk",
-    "gov.pl",
-    "orkanger.no",
-    "gov.pn",
-    "gov.pr",
-    "gov.ps",
-    "gov.pt",
-    "gov.py",
-    "\u{6148}\u{5584}",
-    "nerima.tokyo.jp",
-    "svelvik.no",
-    "elverum.no",
-    "org.om",
-    "skaun.no",
-    "\u{5fb3}\u{5cf6}.jp",
-    "ac.ni",
-    "showa.yamanashi.jp",
-    "daisen.akita.jp",
-    "skanland.no",
-    "ac.nz",
-    "lowicz.pl",
-    "mordovia.su",
-    "kosher",
-    "bizen.okayama.jp",
-    "gouv.ml",
-    "health.vn",
-    "yamal.ru",
-    "rel.pl",
-    "tama.tokyo.jp",
-    "i.se",
-    "theater",
-    "hidaka.kochi.jp",
-    "\u{0627}\u{0644}\u{0639}\u{0644}\u{064a}\u{0627}\u{0646}",
-    "lebork.pl",
-    "overhalla.no",
-    "int.rw",
-    "int.ru",
-    "kunohe.iwate.jp",
-    "kin.okinawa.jp",
-    "ch.it",
-    "moscow.museum",
-    "yamanashi.yamanashi.jp",
-    "nyuzen.toyama.jp",
-    "kuchinotsu.nagasaki.jp",
-    "orange",
-    "ma.us",
-    "manx.museum",
-    "sekigahara.gifu.jp",
-    "homes",
-    "trentinoaltoadige.it",
-    "os\u{00f8}yro.no",
-    "kalisz.pl",
-    "minamiashigara.kanagawa.jp",
-    "money.museum",
-    "sibenik.museum",
-    "aw",
-    "rotorcraft.aero",
-    "kunitomi.miyazaki.jp",
-    "katsuragi.nara.jp",
-    "dolls.museum",
-    "beskidy.pl",
-    "ad.jp",
-    "kurgan.su",
-    "afamilycompany",
-    "jerusalem.museum",
-    "tokushima.jp",
-    "przeworsk.pl",
-    "gov.my",
-    "nokia",
-    "kokonoe.oita.jp",
-    "gov.ms",
-    "gov.mr",
-    "gov.mw",
-    "gov.mv",
-    "gov.mu",
-    "gov.mk",
-    "gov.mo",
-    "gov.mn",
-    "gov.ml",
-    "name.hr",
-    "qld.au",
-    "gov.ma",
-    "gov.mg",
-    "adachi.tokyo.jp",
-    "gov.me",
-    "insure",
-    "center",
-    "int.tj",
-    "watarai.mie.jp",
-    "naklo.pl",
-    "oregon.museum",
-    "nuernberg.museum",
-    "shichikashuku.miyagi.jp",
-    "gallup",
-    "shirosato.ibaraki.jp",
-    "net.st",
-    "works.aero",
-    "net.sy",
-    "exchange.aero",
-    "hagebostad.no",
-    "net.sc",
-    "clinic",
-    "net.sa",
-    "net.sg",
-    "net.sd",
-    "net.sh",
-    "co.tj",
-    "bilbao.museum",
-    "net.so",
-    "net.sl",
-    "hitoyoshi.kumamoto.jp",
-    "nagaokakyo.kyoto.jp",
-    "gmo",
-    "ads",
-    "mitaka.tokyo.jp",
-    "ngo.lk",
-    "vr.it",
-    "lupin",
-    "tenei.fukushima.jp",
-    "gmx",
-    "lib.ne.us",
-    "smart",
-    "gob.pk",
-    "gob.pe",
-    "fyi",
-    "gob.pa",
-    "ogaki.gifu.jp",
-    "yonago.tottori.jp",
-    "even\u{00e1}\u{0161}\u{0161}i.no",
-    "k\u{00e5}fjord.no",
-    "like",
-    "works",
-    "miho.ibaraki.jp",
-    "off.ai",
-    "cc.nh.us",
-    "hair",
-    "ninohe.iwate.jp",
-    "boleslawiec.pl",
-    "misasa.tottori.jp",
-    "workinggroup.aero",
-    "esq",
-    "arkhangelsk.su",
-    "tagajo.miyagi.jp",
-    "kristiansand.no",
-    "kumano.hiroshima.jp",
-    "press.ma",
-    "\u{516c}\u{53f8}",
-    "mil.ru",
-    "mil.rw",
-    "molise.it",
-    "lipetsk.ru",
-    "\u{0430}\u{043a}.\u{0441}\u{0440}\u{0431}",
-    "host",
-    "saotome.st",
-    "tsugaru.aomori.jp",
-    "saskatchewan.museum",
-    "chuo.fukuoka.jp",
-    "nishiizu.shizuoka.jp",
-    "\u{0627}\u{0644}\u{064a}\u{0645}\u{0646}",
-    "togitsu.nagasaki.jp",
-    "lodingen.no",
-    "info.mv",
-    "isumi.chiba.jp",
-    "you",
-    "cc.wv.us",
-    "fujikawa.yamanashi.jp",
-    "ms.kr",
-    "historisches.museum",
-    "asti.it",
-    "com.lv",
-    "com.lk",
-    "ringsaker.no",
-    "com.la",
-    "nagoya",
-    "com.lc",
-    "com.lb",
-    "pors\u{00e1}\u{014b}gu.no",
-    "\u{00e1}laheadju.no",
-    "ski.no",
-    "dnepropetrovsk.ua",
-    "cc.nj.us",
-    "essex.museum",
-    "komforb.se",
-    "kumamoto.jp",
-    "kvafjord.no",
-    "asahi.ibaraki.jp",
-    "cc.id.us",
-    "priv.me",
-    "finland.museum",
-    "utazu.kagawa.jp",
-    "buryatia.ru",
-    "otama.fukushima.jp",
-    "flir",
-    "k12.nj.us",
-    "ed.ci",
-    "pid",
-    "ri.us",
-    "accenture",
-    "halden.no",
-    "ed.cr",
-    "nationalheritage.museum",
-    "edu.om",
-    "\u{092d}\u{093e}\u{0930}\u{0924}",
-    "honjo.saitama.jp",
-    "sakura.chiba.jp",
-    "seranishi.hiroshima.jp",
-    "chieti.it",
-    "asso.gp",
-    "moseushi.hokkaido.jp",
-    "lt.ua",
-    "ubs",
-    "nakamura.kochi.jp",
-    "semine.miyagi.jp",
-    "ravenna.it",
-    "chuo.tokyo.jp",
-    "monzaebrianza.it",
-    "net.pt",
-    "philips",
-    "lenvik.no",
-    "co.st",
-    "ask\u{00f8}y.no",
-    "co.sz",
-    "k12.ga.us",
-    "ishigaki.okinawa.jp",
-    "pz.it",
-    "limited",
-    "\u{4f50}\u{8cc0}.jp",
-    "carraramassa.it",
-    "imizu.toyama.jp",
-    "chirurgiens-dentistes.fr",
-    "munakata.fukuoka.jp",
-    "philately.museum",
-    "elburg.museum",
-    "gov.to",
-    "nyc.museum",
-    "sveio.no",
-    "paris",
-    "gov.tj",
-    "tvedestrand.no",
-    "int.is",
-    "emerck",
-    "ski.museum",
-    "cultural.museum",
-    "lillehammer.no",
-    "kotohira.kagawa.jp",
-    "nishihara.okinawa.jp",
-    "sch.ae",
-    "school",
-    "karasuyama.tochigi.jp",
-    "saarland",
-    "higashine.yamagata.jp",
-    "itabashi.tokyo.jp",
-    "grimstad.no",
-    "aosta.it",
-    "bu.no",
-    "wiw.gov.pl",
-    "misato.wakayama.jp",
-    "edogawa.tokyo.jp",
-    "magadan.ru",
-    "blue",
-    "galsa.no",
-    "hemnes.no",
-    "holtalen.no",
-    "statoil",
-    "\u{718a}\u{672c}.jp",
-    "misaki.okayama.jp",
-    "supplies",
-    "gov.vn",
-    "gov.vc",
-    "gov.ve",
-    "gs.hl.no",
-    "studio",
-    "iglesias-carbonia.it",
-    "co.gl",
-    "saijo.ehime.jp",
-    "immo",
-    "forum",
-    "auction",
-    "saratov.ru",
-    "kakogawa.hyogo.jp",
-    "nakamichi.yamanashi.jp",
-    "net.ng",
-    "net.nf",
-    "avianca",
-    "net.ni",
-    "kasuya.fukuoka.jp",
-    "cc.ct.us",
-    "bolzano.it",
-    "xperia",
-    "net.nr",
-    "alvdal.no",
-    "net.nz",
-    "sandvikcoromant",
-    "k12.nv.us",
-    "groundhandling.aero",
-    "shiso.hyogo.jp",
-    "pro.om",
-    "massacarrara.it",
-    "monzabrianza.it",
-    "tsumagoi.gunma.jp",
-    "smile",
-    "kashiba.nara.jp",
-    "seoul.kr",
-    "ac.lk",
-    "sos.pl",
-    "me",
-    "md",
-    "psc.br",
-    "ma",
-    "trentinostirol.it",
-    "yasuoka.nagano.jp",
-    "ml",
-    "mo",
-    "mn",
-    "mh",
-    "mk",
-    "mu",
-    "mt",
-    "mw",
-    "kaminokawa.tochigi.jp",
-    "mq",
-    "mp",
-    "ms",
-    "mr",
-    "in.th",
-    "my",
-    "mx",
-    "baidu",
-    "grandrapids.museum",
-    "estate",
-    "odo.br",
-    "monza.it",
-    "otaki.nagano.jp",
-    "\u{53f0}\u{6e7e}",
-    "org.sb",
-    "poker",
-    "hyllestad.no",
-    "russia.museum",
-    "swiebodzin.pl",
-    "hareid.no",
-    "nike",
-    "k12.nc.us",
-    "port.fr",
-    "kanie.aichi.jp",
-    "plumbing",
-    "rubtsovsk.ru",
-    "pro.ec",
-    "london",
-    "mtpc",
-    "troandin.no",
-    "takasu.hokkaido.jp",
-    "ochi.kochi.jp",
-    "vinnica.ua",
-    "or.id",
-    "kyowa.akita.jp",
-    "name.vn",
-    "harstad.no",
-    "shizuoka.jp",
-    "b\u{00e1}hccavuotna.no",
-    "biz.pr",
-    "trani-barletta-andria.it",
-    "rnd.ru",
-    "mishima.fukushima.jp",
-    "komatsushima.tokushima.jp",
-    "taiwa.miyagi.jp",
-    "miyoshi.tokushima.jp",
-    "rishirifuji.hokkaido.jp",
-    "net.qa",
-    "goo",
-    "theater.museum",
-    "komaki.aichi.jp",
-    "berlin.museum",
-    "got",
-    "gov",
-    "chernovtsy.ua",
-    "gop",
-    "\u{7db2}\u{7edc}.hk",
-    "free",
-    "dvag",
-    "lib.ma.us",
-    "management",
-    "clubmed",
-    "gob.ni",
-    "gildeskal.no",
-    "mari-el.ru",
-    "sarpsborg.no",
-    "nhk",
-    "kamitsue.oita.jp",
-    "nissedal.no",
-    "hof.no",
-    "shintoku.hokkaido.jp",
-    "ohkura.yamagata.jp",
-    "\u{043c}\u{043e}\u{043d}",
-    "stat.no",
-    "paroch.k12.ma.us",
-    "press.cy",
-    "buzen.fukuoka.jp",
-    "\u{8d2d}\u{7269}",
-    "nanbu.yamanashi.jp",
-    "cheltenham.museum",
-    "qsl.br",
-    "hangout",
-    "reggioemilia.it",
-    "umb.it",
-    "otaki.chiba.jp",
-    "trentino-sud-tirol.it",
-    "info.ki",
-    "katano.osaka.jp",
-    "bg.it",
-    "hiraizumi.iwate.jp",
-    "utsunomiya.tochigi.jp",
-    "fashion",
-    "freemasonry.museum",
-    "lakas.hu",
-    "eidsberg.no",
-    "ms.it",
-    "marburg.museum",
-    "broadcast.museum",
-    "catanzaro.it",
-    "pavia.it",
-    "lifeinsurance",
-    "pippu.hokkaido.jp",
-    "netbank",
-    "koya.wakayama.jp",
-    "village.museum",
-    "ca.us",
-    "academy",
-    "k12.il",
-    "rs.ba",
-    "ebino.miyazaki.jp",
-    "snillfjord.no",
-    "\u{4f01}\u{4e1a}",
-    "yasaka.nagano.jp",
-    "bridgestone",
-    "rogers",
-    "takahama.aichi.jp",
-    "stavanger.no",
-    "allstate",
-    "teramo.it",
-    "um.gov.pl",
-    "yamashina.kyoto.jp",
-    "*.er",
-    "\u{0443}\u{043f}\u{0440}.\u{0441}\u{0440}\u{0431}",
-    "minamidaito.okinawa.jp",
-    "iron.museum",
-    "lomza.pl",
-    "honjyo.akita.jp",
-    "solutions",
-    "earth",
-    "kokubunji.tokyo.jp",
-    "edu.mw",
-    "edu.mv",
-    "edu.mt",
-    "edu.ms",
-    "consulado.st",
-    "ha.no",
-    "portland.museum",
-    "\u{5e7f}\u{4e1c}",
-    "edu.my",
-    "edu.mx",
-    "edu.mg",
-    "edu.me",
-    "wakuya.miyagi.jp",
-    "taishin.fukushima.jp",
-    "edu.mo",
-    "edu.mn",
-    "edu.ml",
-    "edu.mk",
-    "watch",
-    "zamami.okinawa.jp",
-    "rec.br",
-    "wedding",
-    "report",
-    "\u{5065}\u{5eb7}",
-    "com.na",
-    "com.ng",
-    "com.nf",
-    "com.ni",
-    "tsubame.niigata.jp",
-    "com.nr",
-    "3.bg",
-    "tonsberg.no",
-    "iizuna.nagano.jp",
-    "valle-daosta.it",
-    "ueda.nagano.jp",
-    "kazan.ru",
-    "midsund.no",
-    "westfalen.museum",
-    "panerai",
-    "store.nf",
-    "emilia-romagna.it",
-    "anpachi.gifu.jp",
-    "takino.hyogo.jp",
-    "bargains",
-    "viking.museum",
-    "gon.pk",
-    "news",
-    "tushu",
-    "mi.th",
-    "rimini.it",
-    "lu.it",
-    "games",
-    "utah.museum",
-    "rivne.ua",
-    "trust",
-    "kawasaki.miyagi.jp",
-    "bingo",
-    "kamifurano.hokkaido.jp",
-    "newholland",
-    "fuso.aichi.jp",
-    "beer",
-    "pp.ru",
-    "judygarland.museum",
-    "matsuyama.ehime.jp",
-    "gov.tt",
-    "gov.tw",
-    "loft",
-    "gov.tr",
-    "xxx",
-    "zp.gov.pl",
-    "gov.tl",
-    "gov.tm",
-    "gov.tn",
-    "cal.it",
-    "sor-fron.no",
-    "lodi.it",
-    "pilot.aero",
-    "nara.nara.jp",
-    "rome.it",
-    "trapani.it",
-    "allfinanz",
-    "net.lk",
-    "org.sz",
-    "org.sy",
-    "si.it",
-    "org.sv",
-    "org.st",
-    "net.lc",
-    "net.lb",
-    "net.la",
-    "org.so",
-    "org.sn",
-    "org.sl",
-    "net.ly",
-    "org.sh",
-    "org.sg",
-    "net.lv",
-    "org.se",
-    "org.sd",
-    "org.sc",
-    "net.lr",
-    "org.sa",
-    "pro.mv",
-    "ama.aichi.jp",
-    "moroyama.saitama.jp",
-    "gamagori.aichi.jp",
-    "shoo.okayama.jp",
-    "chukotka.ru",
-    "aogashima.tokyo.jp",
-    "lib.ms.us",
-    "ac.be",
-    "podhale.pl",
-    "\u{6211}\u{7231}\u{4f60}",
-    "ingatlan.hu",
-    "arab",
-    "trentinoa-adige.it",
-    "osakasayama.osaka.jp",
-    "jeonnam.kr",
-    "fukumitsu.toyama.jp",
-    "lombardy.it",
-    "toray",
-    "british.museum",
-    "gov.bf",
-    "coop.km",
-    "bearalv\u{00e1}hki.no",
-    "vologda.su",
-    "pharmacy",
-    "\u{30b0}\u{30fc}\u{30b0}\u{30eb}",
-    "pe.ca",
-    "\u{00e5}fjord.no",
-    "gov.bb",
-    "kuzbass.ru",
-    "udmurtia.ru",
-    "mombetsu.hokkaido.jp",
-    "scotland.museum",
-    "tosashimizu.kochi.jp",
-    "africa",
-    "rep.kp",
-    "k12.tx.us",
-    "arai.shizuoka.jp",
-    "ao.it",
-    "gift",
-    "oz.au",
-    "schwarz",
-    "meland.no",
-    "square.museum",
-    "kamioka.akita.jp",
-    "public.museum",
-    "troms\u{00f8}.no",
-    "conference.aero",
-    "cyber.museum",
-    "tanabe.kyoto.jp",
-    "ice",
-    "andriatranibarletta.it",
-    "m\u{00e1}tta-v\u{00e1}rjjat.no",
-    "fujimi.saitama.jp",
-    "p.se",
-    "toba.mie.jp",
-    "christmas",
-    "booking",
-    "or.kr",
-    "aomori.jp",
-    "randaberg.no",
-    "name.tt",
-    "name.tr",
-    "discount",
-    "name.tj",
-    "seihi.nagasaki.jp",
-    "trolley.museum",
-    "hokuto.yamanashi.jp",
-    "jewish.museum",
-    "go.it",
-    "pordenone.it",
-    "plus",
-    "go.id",
-    "\u{5a31}\u{4e50}",
-    "imageandsound.museum",
-    "yaroslavl.ru",
-    "web.co",
-    "eco.br",
-    "nm.us",
-    "southwest.museum",
-    "mandal.no",
-    "perso.sn",
-    "edu.vc",
-    "kochi.jp",
-    "virgin",
-    "edu.vu",
-    "mup.gov.pl",
-    "milano.it",
-    "kv\u{00e6}fjord.no",
-    "if.ua",
-    "bd.se",
-    "yonezawa.yamagata.jp",
-    "ostrowiec.pl",
-    "kadogawa.miyazaki.jp",
-    "naka.ibaraki.jp",
-    "egyptian.museum",
-    "solar",
-    "alt.za",
-    "cc.tn.us",
-    "viva",
-    "australia.museum",
-    "vivo",
-    "qc.ca",
-    "id.us",
-    "\u{5927}\u{4f17}\u{6c7d}\u{8f66}",
-    "tomika.gifu.jp",
-    "chel.ru",
-    "rovigo.it",
-    "shiraoi.hokkaido.jp",
-    "cahcesuolo.no",
-    "pomorskie.pl",
-    "valled-aosta.it",
-    "pub.sa",
-    "\u{654e}\u{80b2}.hk",
-    "nl",
-    "no",
-    "m.bg",
-    "na",
-    "nc",
-    "ne",
-    "nf",
-    "ng",
-    "nz",
-    "passagens",
-    "nr",
-    "lib.nv.us",
-    "nu",
-    "x.se",
-    "ky.us",
-    "masoy.no",
-    "football",
-    "mb.it",
-    "shibetsu.hokkaido.jp",
-    "spb.su",
-    "univ.sn",
-    "komvux.se",
-    "telefonica",
-    "olbia-tempio.it",
-    "dazaifu.fukuoka.jp",
-    "yamakita.kanagawa.jp",
-    "otofuke.hokkaido.jp",
-    "coach",
-    "omaha.museum",
-    "yamatokoriyama.nara.jp",
-    "takasago.hyogo.jp",
-    "sakata.yamagata.jp",
-    "click",
-    "sakegawa.yamagata.jp",
-    "mat.br",
-    "nishitosa.kochi.jp",
-    "etne.no",
-    "gjemnes.no",
-    "coop",
-    "gen.tr",
-    "matsuura.nagasaki.jp",
-    "kawanabe.kagoshima.jp",
-    "kawatana.nagasaki.jp",
-    "yashiro.hyogo.jp",
-    "geology.museum",
-    "mma",
-    "brother",
-    "mi.us",
-    "computer.museum",
-    "icu",
-    "movie",
-    "chikuzen.fukuoka.jp",
-    "gorlice.pl",
-    "ri.it",
-    "gs.nl.no",
-    "padua.it",
-    "porn",
-    "usuki.oita.jp",
-    "asso.ci",
-    "salvadordali.museum",
-    "hemne.no",
-    "edu.kp",
-    "edu.ky",
-    "edu.kz",
-    "pisa.it",
-    "n\u{00e1}vuotna.no",
-    "toyohashi.aichi.jp",
-    "edu.kg",
-    "shirakawa.gifu.jp",
-    "edu.ki",
-    "edu.km",
-    "edu.kn",
-    "cc.fl.us",
-    "sakura.tochigi.jp",
-    "baseball",
-    "kolobrzeg.pl",
-    "kasugai.aichi.jp",
-    "santafe.museum",
-    "portal.museum",
-    "ts.it",
-    "nishiawakura.okayama.jp",
-    "modena.it",
-    "puglia.it",
-    "sakhalin.ru",
-    "sande.m\u{00f8}re-og-romsdal.no",
-    "lazio.it",
-    "compare",
-    "tmp.br",
-    "k12.pr.us",
-    "raisa.no",
-    "tone.ibaraki.jp",
-    "setouchi.okayama.jp",
-    "lyngdal.no",
-    "sener",
-    "msd",
-    "today",
-    "ltd.lk",
-    "symantec",
-    "gv.at",
-    "bremanger.no",
-    "\u{5bae}\u{5d0e}.jp",
-    "gv.ao",
-    "historisch.museum",
-    "\u{5929}\u{4e3b}\u{6559}",
-    "her\u{00f8}y.nordland.no",
-    "\u{798f}\u{5cf6}.jp",
-    "snz.ru",
-    "*.gu",
-    "k12.tn.us",
-    "nu.ca",
-    "katashina.gunma.jp",
-    "stream",
-    "froya.no",
-    "sorreisa.no",
-    "eng.br",
-    "sharp",
-    "reggio-emilia.it",
-    "sa.gov.au",
-    "ruovat.no",
-    "amsterdam.museum",
-    "\u{6fb3}\u{95e8}",
-    "en.it",
-    "kurogi.fukuoka.jp",
-    "\u{7f51}\u{7d61}.hk",
-    "atlanta.museum",
-    "ok.us",
-    "\u{6fb3}\u{9580}",
-    "biz.az",
-    "gov.za",
-    "k12.ma.us",
-    "usdecorativearts.museum",
-    "cc.oh.us",
-    "rm.it",
-    "bronnoysund.no",
-    "org.uy",
-    "org.uz",
-    "net.jo",
-    "hizen.saga.jp",
-    "obanazawa.yamagata.jp",
-    "net.je",
-    "tomakomai.hokkaido.jp",
-    "org.uk",
-    "ota.gunma.jp",
-    "hotel.tz",
-    "org.ug",
-    "sola.no",
-    "kushimoto.wakayama.jp",
-    "nov.su",
-    "l\u{00e6}rdal.no",
-    "gifu.gifu.jp",
-    "cc.ms.us",
-    "chungnam.kr",
-    "miharu.fukushima.jp",
-    "orskog.no",
-    "payu",
-    "name",
-    "vdonsk.ru",
-    "tamaki.mie.jp",
-    "lacaixa",
-    "per.sg",
-    "t.bg",
-    "science.museum",
-    "sex.hu",
-    "hornindal.no",
-    "kpmg",
-    "coop.mw",
-    "coop.mv",
-    "aa.no",
-    "skjerv\u{00f8}y.no",
-    "shangrila",
-    "zappos",
-    "\u{00e5}seral.no",
-    "botany.museum",
-    "filatelia.museum",
-    "austevoll.no",
-    "edu.ve",
-    "y.se",
-    "dallas.museum",
-    "urbino-pesaro.it",
-    "niepce.museum",
-    "edu.vn",
-    "place",
-    "daegu.kr",
-    "oshima.yamaguchi.jp",
-    "lasalle",
-    "steigen.no",
-    "nic.in",
-    "hiji.oita.jp",
-    "george",
-    "engineer",
-    "airbus",
-    "hb.cn",
-    "cng.br",
-    "chiyoda.tokyo.jp",
-    "kamisunagawa.hokkaido.jp",
-    "or.mu",
-    "isernia.it",
-    "ngo.ph",
-    "stargard.pl",
-    "go.kr",
-    "moss.no",
-    "santacruz.museum",
-    "clock.museum",
-    "suwalki.pl",
-    "nature.museum",
-    "chloe",
-    "pro",
-    "pru",
-    "itako.ibaraki.jp",
-    "ibaraki.osaka.jp",
-    "rent",
-    "skjervoy.no",
-    "anz",
-    "aoki.nagano.jp",
-    "police.uk",
-    "datsun",
-    "edu.tj",
-    "charter.aero",
-    "edu.to",
-    "edu.tr",
-    "surnadal.no",
-    "edu.tw",
-    "gmail",
-    "surf",
-    "karikatur.museum",
-    "mazowsze.pl",
-    "cc.or.us",
-    "gz.cn",
-    "gojome.akita.jp",
-    "j\u{00f8}rpeland.no",
-    "ut.us",
-    "bahccavuotna.no",
-    "frogans",
-    "sohu",
-    "sciencecenter.museum",
-    "lib.sd.us",
-    "tj.cn",
-    "cheap",
-    "matsumoto.kagoshima.jp",
-    "oyama.tochigi.jp",
-    "dyr\u{00f8}y.no",
-    "notogawa.shiga.jp",
-    "kazo.saitama.jp",
-    "frontdoor",
-    "fauske.no",
-    "yamanashi.jp",
-    "taito.tokyo.jp",
-    "naturalhistorymuseum.museum",
-    "psi.br",
-    "mormon",
-    "nasushiobara.tochigi.jp",
-    "wakasa.tottori.jp",
-    "bruxelles.museum",
-    "lorenskog.no",
-    "koga.ibaraki.jp",
-    "tatebayashi.gunma.jp",
-    "cc.mt.us",
-    "gjerstad.no",
-    "flog.br",
-    "kitanakagusuku.okinawa.jp",
-    "kamo.kyoto.jp",
-    "watchandclock.museum",
-    "cb.it",
-    "torsken.no",
-    "niihama.ehime.jp",
-    "gateway.museum",
-    "phoenix.museum",
-    "oamishirasato.chiba.jp",
-    "homegoods",
-    "tysnes.no",
-    "maintenance.aero",
-    "oguchi.aichi.jp",
-    "sumida.tokyo.jp",
-    "mortgage",
-    "farmers",
-    "review",
-    "dinosaur.museum",
-    "\u{067e}\u{0627}\u{06a9}\u{0633}\u{062a}\u{0627}\u{0646}",
-    "nanyo.yamagata.jp",
-    "k12.ar.us",
-    "srv.br",
-    "gallery.museum",
-    "hazu.aichi.jp",
-    "ltd.co.im",
-    "kitaakita.akita.jp",
-    "hs.kr",
-    "yamada.fukuoka.jp",
-    "kaita.hiroshima.jp",
-    "varggat.no",
-    "music.museum",
-    "iinet",
-    "konan.aichi.jp",
-    "nakama.fukuoka.jp",
-    "tokoname.aichi.jp",
-    "takagi.nagano.jp",
-    "hashikami.aomori.jp",
-    "surrey.museum",
-    "asso.mc",
-    "murata.miyagi.jp",
-    "transport.museum",
-    "gs.tm.no",
-    "saga.saga.jp",
-    "defense.tn",
-    "edu.is",
-    "edu.iq",
-    "m\u{00e1}latvuopmi.no",
-    "edu.it",
-    "valdaosta.it",
-    "edu.in",
-    "tomobe.ibaraki.jp",
-    "lig.it",
-    "ine.kyoto.jp",
-    "\u{043e}\u{0434}.\u{0441}\u{0440}\u{0431}",
-    "glas.museum",
-    "warabi.saitama.jp",
-    "zippo",
-    "shinkamigoto.nagasaki.jp",
-    "mochizuki.nagano.jp",
-    "gs.va.no",
-    "aquarelle",
-    "case",
-    "hughes",
-    "com.bt",
-    "com.bs",
-    "com.br",
-    "artgallery.museum",
-    "com.bz",
-    "com.by",
-    "career",
-    "nanae.hokkaido.jp",
-    "com.bb",
-    "com.ba",
-    "com.bo",
-    "com.bm",
-    "kobayashi.miyazaki.jp",
-    "com.bi",
-    "com.bh",
-    "nt.ro",
-    "sanfrancisco.museum",
-    "vuelos",
-    "net.bo",
-    "okegawa.saitama.jp",
-    "inatsuki.fukuoka.jp",
-    "lib.la.us",
-    "author",
-    "yakage.okayama.jp",
-    "stockholm.museum",
-    "net.br",
-    "jogasz.hu",
-    "coop.py",
-    "net.bt",
-    "barefoot",
-    "ozu.kumamoto.jp",
-    "bbs.tr",
-    "civilization.museum",
-    "uconnect",
-    "xj.cn",
-    "halloffame.museum",
-    "boehringer",
-    "fermo.it",
-    "porsgrunn.no",
-    "wakayama.jp",
-    "zuerich",
-    "r\u{00f8}d\u{00f8}y.no",
-    "tadaoka.osaka.jp",
-    "missile.museum",
-    "b\u{00e1}jddar.no",
-    "ikusaka.nagano.jp",
-    "mer\u{00e5}ker.no",
-    "club.tw",
-    "ureshino.mie.jp",
-    "yamato.fukushima.jp",
-    "kutno.pl",
-    "k12.ny.us",
-    "trentinosud-tirol.it",
-    "blog",
-    "kawaba.gunma.jp",
-    "yawatahama.ehime.jp",
-    "arts.ve",
-    "is.it",
-    "cc.ut.us",
-    "mitoyo.kagawa.jp",
-    "biz.cy",
-    "lanbib.se",
-    "furniture",
-    "ah.no",
-    "minamiechizen.fukui.jp",
-    "mashike.hokkaido.jp",
-    "nissan",
-    "selje.no",
-    "nes.buskerud.no",
-    "izumi.osaka.jp",
-    "toride.ibaraki.jp",
-    "nissay",
-    "montreal.museum",
-    "web.za",
-    "al.us",
-    "gs.mr.no",
-    "net.ht",
-    "madrid",
-    "ardal.no",
-    "mo.us",
-    "net.hk",
-    "net.hn",
-    "e164.arpa",
-    "london.museum",
-    "legal",
-    "kamagaya.chiba.jp",
-    "co.us",
-    "kepno.pl",
-    "nishiokoppe.hokkaido.jp",
-    "\u{0641}\u{0644}\u{0633}\u{0637}\u{064a}\u{0646}",
-    "org.af",
-    "ind.in",
-    "hatoyama.saitama.jp",
-    "om",
-    "zentsuji.kagawa.jp",
-    "nc.us",
-    "date.hokkaido.jp",
-    "elblag.pl",
-    "nom.ni",
-    "orkdal.no",
-    "cartier",
-    "fie.ee",
-    "yukuhashi.fukuoka.jp",
-    "mjondalen.no",
-    "radoy.no",
-    "higashiosaka.osaka.jp",
-    "ichinoseki.iwate.jp",
-    "minamata.kumamoto.jp",
-    "minami.fukuoka.jp",
-    "bindal.no",
-    "sec.ps",
-    "schaeffler",
-    "tsurugi.ishikawa.jp",
-    "kagami.kochi.jp",
-    "trani-andria-barletta.it",
-    "sumoto.hyogo.jp",
-    "fish",
-    "cherkassy.ua",
-    "educational.museum",
-    "juif.museum",
-    "\u{8054}\u{901a}",
-    "zt.ua",
-    "artdeco.museum",
-    "kiwa.mie.jp",
-    "ogose.saitama.jp",
-    "org.ws",
-    "\u{00e5}rdal.no",
-    "sakahogi.gifu.jp",
-    "naturalsciences.museum",
-    "cba",
-    "tambov.ru",
-    "beiarn.no",
-    "avoues.fr",
-    "cbs",
-    "misato.shimane.jp",
-    "inabe.mie.jp",
-    "toon.ehime.jp",
-    "ome.tokyo.jp",
-    "hl.no",
-    "kerryhotels",
-    "kawanishi.hyogo.jp",
-    "name.pr",
-    "taa.it",
-    "law.za",
-    "ikano",
-    "rollag.no",
-    "nagai.yamagata.jp",
-    "tsubata.ishikawa.jp",
-    "ekloges.cy",
-    "dog",
-    "barcelona",
-    "rifu.miyagi.jp",
-    "directory",
-    "colonialwilliamsburg.museum",
-    "togakushi.nagano.jp",
-    "k12.la.us",
-    "ambulance.museum",
-    "kawaguchi.saitama.jp",
-    "entomology.museum",
-    "chita.ru",
-    "rygge.no",
-    "florence.it",
-    "gob.hn",
-    "chtr.k12.ma.us",
-    "med.om",
-    "edu.rs",
-    "motorcycle.museum",
-    "cnt.br",
-    "edu.rw",
-    "edu.ru",
-    "global",
-    "song",
-    "fan",
-    "drobak.no",
-    "sony",
-    "meldal.no",
-    "matsubara.osaka.jp",
-    "watch-and-clock.museum",
-    "id.ir",
-    "glogow.pl",
-    "wsa.gov.pl",
-    "journalist.aero",
-    "tel",
-    "school.museum",
-    "r\u{00f8}ros.no",
-    "habikino.osaka.jp",
-    "bt.it",
-    "design",
-    "nba",
-    "\u{0645}\u{0635}\u{0631}",
-    "sondrio.it",
-    "ivano-frankivsk.ua",
-    "unnan.shimane.jp",
-    "date.fukushima.jp",
-    "s\u{00f8}r-fron.no",
-    "mil.za",
-    "tsukiyono.gunma.jp",
-    "racing",
-    "sld.do",
-    "horology.museum",
-    "storfjord.no",
-    "f.se",
-    "shitara.aichi.jp",
-    "neyagawa.osaka.jp",
-    "happou.akita.jp",
-    "modalen.no",
-    "anjo.aichi.jp",
-    "kuroiso.tochigi.jp",
-    "\u{0dbd}\u{0d82}\u{0d9a}\u{0dcf}",
-    "shikaoi.hokkaido.jp",
-    "hermes",
-    "crafts.museum",
-    "markets",
-    "nirasaki.yamanashi.jp",
-    "onna.okinawa.jp",
-    "nakatane.kagoshima.jp",
-    "mishima.shizuoka.jp",
-    "h\u{00e1}mm\u{00e1}rfeasta.no",
-    "md.us",
-    "sanjo.niigata.jp",
-    "virtual.museum",
-    "mw.gov.pl",
-    "firm.co",
-    "me.uk",
-    "hinohara.tokyo.jp",
-    "me.us",
-    "dnipropetrovsk.ua",
-    "nakano.nagano.jp",
-    "foundation.museum",
-    "mil.ni",
-    "fujishiro.ibaraki.jp",
-    "nogi.tochigi.jp",
-    "ninomiya.kanagawa.jp",
-    "inder\u{00f8}y.no",
-    "of.by",
-    "imabari.ehime.jp",
-    "neues.museum",
-    "mihara.kochi.jp",
-    "am.br",
-    "org.tw",
-    "war.museum",
-    "lighting",
-    "mizusawa.iwate.jp",
-    "horonobe.hokkaido.jp",
-    "org.tj",
-    "iwamizawa.hokkaido.jp",
-    "com.dz",
-    "a.bg",
-    "alta.no",
-    "vegas",
-    "k12.ne.us",
-    "sherbrooke.museum",
-    "rentals",
-    "nisshin.aichi.jp",
-    "kaho.fukuoka.jp",
-    "int.ar",
-    "tobishima.aichi.jp",
-    "int.az",
-    "nakijin.okinawa.jp",
-    "sch.ir",
-    "lierne.no",
-    "pg.it",
-    "sale",
-    "med.ec",
-    "scot",
-    "sch.id",
-    "cool",
-    "trondheim.no",
-    "embetsu.hokkaido.jp",
-    "oharu.aichi.jp",
-    "kautokeino.no",
-    "miyako.fukuoka.jp",
-    "*.kw",
-    "sandefjord.no",
-    "bestbuy",
-    "sciences.museum",
-    "shiogama.miyagi.jp",
-    "*.ke",
-    "unicom",
-    "vevelstad.no",
-    "nago.okinawa.jp",
-    "*.kh",
-    "kafjord.no",
-    "aseral.no",
-    "florist",
-    "ushistory.museum",
-    "tokai.aichi.jp",
-    "higashinaruse.akita.jp",
-    "r\u{00e6}lingen.no",
-    "sologne.museum",
-    "lib.tx.us",
-    "venezia.it",
-    "multichoice",
-    "center.museum",
-    "yatsuka.shimane.jp",
-    "health",
-    "okawa.kochi.jp",
-    "balashov.su",
-    "qpon",
-    "ce.it",
-    "inuyama.aichi.jp",
-    "biz.et",
-    "b.se",
-    "kakinoki.shimane.jp",
-    "landrover",
-    "oristano.it",
-    "\u{00e1}lt\u{00e1}.no",
-    "shinjuku.tokyo.jp",
-    "folldal.no",
-    "kumatori.osaka.jp",
-    "\u{4e2a}\u{4eba}.hk",
-    "vega.no",
-    "ivanovo.ru",
-    "championship.aero",
-    "sumoto.kumamoto.jp",
-    "naamesjevuemie.no",
-    "oguni.kumamoto.jp",
-    "\u{00e5}lg\u{00e5}rd.no",
-    "watches",
-    "shimoji.okinawa.jp",
-    "mihama.mie.jp",
-    "mito.ibaraki.jp",
-    "store",
-    "k12.ks.us",
-    "idv.hk",
-    "nonoichi.ishikawa.jp",
-    "hamura.tokyo.jp",
-    "certification.aero",
-    "auspost",
-    "semboku.akita.jp",
-    "kalmykia.ru",
-    "cc.nm.us",
-    "tana.no",
-    "ashoro.hokkaido.jp",
-    "siena.it",
-    "h.bg",
-    "kaneyama.fukushima.jp",
-    "reise",
-    "kasuga.hyogo.jp",
-    "otoyo.kochi.jp",
-    "gorizia.it",
-    "aomori.aomori.jp",
-    "gujo.gifu.jp",
-    "cc.az.us",
-    "ternopil.ua",
-    "mnet",
-    "urn.arpa",
-    "maison",
-    "im.it",
-    "genkai.saga.jp",
-    "trustee.museum",
-    "nakagawa.nagano.jp",
-    "ovre-eiker.no",
-    "it.ao",
-    "kasai.hyogo.jp",
-    "skierv\u{00e1}.no",
-    "tagami.niigata.jp",
-    "windows",
-    "tt.im",
-    "dentist",
-    "drammen.no",
-    "latrobe",
-    "v\u{00e1}rgg\u{00e1}t.no",
-    "niigata.niigata.jp",
-    "pars",
-    "samsclub",
-    "ato.br",
-    "saga.jp",
-    "taishi.osaka.jp",
-    "\u{57fc}\u{7389}.jp",
-    "christiansburg.museum",
-    "\u{043c}\u{043a}\u{0434}",
-    "paris.museum",
-    "tuva.su",
-    "\u{5cf6}\u{6839}.jp",
-    "plc.uk",
-    "\u{0627}\u{0644}\u{0645}\u{063a}\u{0631}\u{0628}",
-    "or.at",
-    "iwaki.fukushima.jp",
-    "hiv",
-    "a\u{00e9}roport.ci",
-    "rnrt.tn",
-    "yao.osaka.jp",
-    "iwatsuki.saitama.jp",
-    "plo.ps",
-    "amica",
-    "chippubetsu.hokkaido.jp",
-    "budapest",
-    "lib.me.us",
-    "5.bg",
-    "\u{65b0}\u{52a0}\u{5761}",
-    "\u{65f6}\u{5c1a}",
-    "soundandvision.museum",
-    "milan.it",
-    "koeln.museum",
-    "parti.se",
-    "tci",
-    "homebuilt.aero",
-    "federation.aero",
-    "intelligence.museum",
-    "stalbans.museum",
-    "edu.pt",
-    "shima.mie.jp",
-    "edu.pr",
-    "edu.ps",
-    "stord.no",
-    "edu.pe",
-    "edu.pf",
-    "edu.pa",
-    "edu.pl",
-    "edu.pn",
-    "edu.ph",
-    "kitamoto.saitama.jp",
-    "edu.pk",
-    "f.bg",
-    "erni",
-    "gyeonggi.kr",
-    "takanezawa.tochigi.jp",
-    "oiso.kanagawa.jp",
-    "kamikitayama.nara.jp",
-    "desa.id",
-    "obama.fukui.jp",
-    "vibo-valentia.it",
-    "fr\u{00f8}ya.no",
-    "insurance",
-    "hamatonbetsu.hokkaido.jp",
-    "akishima.tokyo.jp",
-    "\u{30bb}\u{30fc}\u{30eb}",
-    "asda",
-    "tyumen.ru",
-    "nat.tn",
-    "pr",
-    "ps",
-    "nadex",
-    "java",
-    "pw",
-    "pt",
-    "pharmacy.museum",
-    "py",
-    "pa",
-    "pf",
-    "pe",
-    "pk",
-    "ph",
-    "pn",
-    "pl",
-    "pm",
-    "ltd.uk",
-    "kasaoka.okayama.jp",
-    "toyama.jp",
-    "student.aero",
-    "okayama.okayama.jp",
-    "baidar.no",
-    "*.pg",
-    "chikugo.fukuoka.jp",
-    "higashiizu.shizuoka.jp",
-    "kred",
-    "info.sd",
-    "nishi.fukuoka.jp",
-    "rip",
-    "rio",
-    "ril",
-    "fukusaki.hyogo.jp",
-    "bo.telemark.no",
-    "yashio.saitama.jp",
-    "skodje.no",
-    "everbank",
-    "panasonic",
-    "tysv\u{00e6}r.no",
-    "redumbrella",
-    "\u{0438}\u{043a}\u{043e}\u{043c}.museum",
-    "agematsu.nagano.jp",
-    "nagatoro.saitama.jp",
-    "flora.no",
-    "orland.no",
-    "\u{067e}\u{0627}\u{0643}\u{0633}\u{062a}\u{0627}\u{0646}",
-    "moka.tochigi.jp",
-    "tohnosho.chiba.jp",
-    "flesberg.no",
-    "campobasso.it",
-    "mosjoen.no",
-    "naturalhistory.museum",
-    "okutama.tokyo.jp",
-    "\u{c0bc}\u{c131}",
-    "nordre-land.no",
-    "tochigi.jp",
-    "press",
-    "lib.pr.us",
-    "pohl",
-    "kwp.gov.pl",
-    "lexus",
-    "reliance",
-    "cn.ua",
-    "gonohe.aomori.jp",
-    "sakai.fukui.jp",
-    "tateyama.toyama.jp",
-    "schokoladen.museum",
-    "askim.no",
-    "vaga.no",
-    "ogasawara.tokyo.jp",
-    "vladikavkaz.ru",
-    "fairwinds",
-    "softbank",
-    "fvg.it",
-    "fukagawa.hokkaido.jp",
-    "kerryproperties",
-    "com.fr",
-    "crimea.ua",
-    "nordreisa.no",
-    "lib.ia.us",
-    "osakikamijima.hiroshima.jp",
-    "altoadige.it",
-    "int.co",
-    "int.ci",
-    "nt.no",
-    "luxe",
-    "newspaper.museum",
-    "k12.ca.us",
-    "prof",
-    "prod",
-    "humanities.museum",
-    "bellevue.museum",
-    "weir",
-    "k12.nm.us",
-    "kushima.miyazaki.jp",
-    "2000.hu",
-    "lib.oh.us",
-    "gov.cn",
-    "yamaxun",
-    "*.mz",
-    "ikeda.osaka.jp",
-    "muosat.no",
-    "vefsn.no",
-    "adygeya.ru",
-    "*.mm",
-    "notodden.no",
-    "aca.pro",
-    "northwesternmutual",
-    "cherkasy.ua",
-    "countryestate.museum",
-    "zj.cn",
-    "sx.cn",
-    "ubank",
-    "fast",
-    "time.museum",
-    "tarumizu.kagoshima.jp",
-    "kitami.hokkaido.jp",
-    "aizumisato.fukushima.jp",
-    "campidanomedio.it",
-    "delta",
-    "!city.kobe.jp",
-    "9.bg",
-    "arts.ro",
-    "sirdal.no",
-    "equipment.aero",
-    "kyknet",
-    "ebina.kanagawa.jp",
-    "tochigi.tochigi.jp",
-    "ug.gov.pl",
-    "mitsue.nara.jp",
-    "eun.eg",
-    "toyoura.hokkaido.jp",
-    "associates",
-    "lans.museum",
-    "toki.gifu.jp",
-    "tsuchiura.ibaraki.jp",
-    "trv",
-    "jfk.museum",
-    "ac.za",
-    "nico",
-    "lucerne.museum",
-    "takata.fukuoka.jp",
-    "\u{30b3}\u{30e0}",
-    "kofu.yamanashi.jp",
-    "\u{624b}\u{8868}",
-    "tromsa.no",
-    "mo\u{00e5}reke.no",
-    "kv\u{00e6}nangen.no",
-    "ky",
-    "net.ai",
-    "treviso.it",
-    "asahi.chiba.jp",
-    "net.ae",
-    "film.hu",
-    "levanger.no",
-    "hakui.ishikawa.jp",
-    "cc.co.us",
-    "vestvagoy.no",
-    "scienceandindustry.museum",
-    "edu",
-    "gouv.ci",
-    "lawyer",
-    "pe.kr",
-    "raholt.no",
-    "an.it",
-    "usa.museum",
-    "fukuroi.shizuoka.jp",
-    "yabu.hyogo.jp",
-    "es.kr",
-    "kujukuri.chiba.jp",
-    "maritimo.museum",
-    "forde.no",
-    "ancona.it",
-    "asaka.saitama.jp",
-    "sayo.hyogo.jp",
-    "abo.pa",
-    "b.bg",
-    "turek.pl",
-    "skydiving.aero",
-    "artanddesign.museum",
-    "!teledata.mz",
-    "net.do",
-    "net.dm",
-    "elk.pl",
-    "opoczno.pl",
-    "grondar.za",
-    "embroidery.museum",
-    "val-d-aosta.it",
-    "net.dz",
-    "midori.gunma.jp",
-    "kusu.oita.jp",
-    "cfd",
-    "edu.bm",
-    "grue.no",
-    "edu.bb",
-    "gmbh",
-    "soni.nara.jp",
-    "himeshima.oita.jp",
-    "\u{8328}\u{57ce}.jp",
-    "or.ci",
-    "mima.tokushima.jp",
-    "w.bg",
-    "sicily.it",
-    "gyeongnam.kr",
-    "rnu.tn",
-    "capital",
-    "or.cr",
-    "k12.mn.us",
-    "airtel",
-    "degree",
-    "lib.vi.us",
-    "cc.pr.us",
-    "fyresdal.no",
-    "mielno.pl",
-    "k12.mo.us",
-    "sexy",
-    "ikeda.gifu.jp",
-    "kozagawa.wakayama.jp",
-    "tv.im",
-    "\u{7f51}\u{5e97}",
-    "fukushima.hokkaido.jp",
-    "hdfcbank",
-    "tatamotors",
-    "sanok.pl",
-    "haga.tochigi.jp",
-    "tv.it",
-    "uto.kumamoto.jp",
-    "etajima.hiroshima.jp",
-    "app",
-    "wuoz.gov.pl",
-    "duck",
-    "molde.no",
-    "kai.yamanashi.jp",
-    "gob.do",
-    "kouzushima.tokyo.jp",
-    "nexus",
-    "8.bg",
-    "muko.kyoto.jp",
-    "cipriani",
-    "kihoku.ehime.jp",
-    "shonai.fukuoka.jp",
-    "pvt.k12.ma.us",
-    "v\u{00e5}ler.hedmark.no",
-    "granvin.no",
-    "int.vn",
-    "pccw",
-    "nedre-eiker.no",
-    "aerobatic.aero",
-    "joyo.kyoto.jp",
-    "tax",
-    "hiraya.nagano.jp",
-    "tachiarai.fukuoka.jp",
-    "navigation.aero",
-    "guru",
-    "canada.museum",
-    "anamizu.ishikawa.jp",
-    "dwg",
-    "jondal.no",
-    "eidsvoll.no",
-    "zao.miyagi.jp",
-    "friuli-venezia-giulia.it",
-    "winners",
-    "firenze.it",
-    "silk.museum",
-    "kumejima.okinawa.jp",
-    "oksnes.no",
-    "villas",
-    "tos.it",
-    "ono.fukushima.jp",
-    "mi.it",
-    "kouyama.kagoshima.jp",
-    "uwajima.ehime.jp",
-    "kasuga.fukuoka.jp",
-    "go.tz",
-    "egersund.no",
-    "norilsk.ru",
-    "yawata.kyoto.jp",
-    "navuotna.no",
-    "meguro.tokyo.jp",
-    "minami.kyoto.jp",
-    "omachi.nagano.jp",
-    "holiday",
-    "rsvp",
-    "kanoya.kagoshima.jp",
-    "enna.it",
-    "ooshika.nagano.jp",
-    "crs",
-    "fujimi.nagano.jp",
-    "pharmaciens.km",
-    "fjaler.no",
-    "griw.gov.pl",
-    "\u{5546}\u{6807}",
-    "shop.hu",
-    "shop.ht",
-    "republican",
-    "lo.it",
-    "americanfamily",
-    "me.it",
-    "konskowola.pl",
-    "nfl",
-    "pyatigorsk.ru",
-    "go.tj",
-    "hasvik.no",
-    "vic.au",
-    "nakagusuku.okinawa.jp",
-    "higashitsuno.kochi.jp",
-    "bentley",
-    "palana.ru",
-    "k12.mt.us",
-    "asso.km",
-    "stateofdelaware.museum",
-    "leirvik.no",
-    "ag.it",
-    "nara.jp",
-    "frontier",
-    "koganei.tokyo.jp",
-    "stj\u{00f8}rdalshalsen.no",
-    "deloitte",
-    "newport.museum",
-    "kawai.nara.jp",
-    "saikai.nagasaki.jp",
-    "nikaho.akita.jp",
-    "co.gy",
-    "ibaraki.jp",
-    "jus.br",
-    "vyatka.ru",
-    "vacations",
-    "co.gg",
-    "agents.aero",
-    "organic",
-    "towada.aomori.jp",
-    "siracusa.it",
-    "ltd",
-    "naval.museum",
-    "higashikurume.tokyo.jp",
-    "ise.mie.jp",
-    "walter",
-    "varoy.no",
-    "toyokawa.aichi.jp",
-    "sci.eg",
-    "s.se",
-    "vig",
-    "kamisato.saitama.jp",
-    "olawa.pl",
-    "higashihiroshima.hiroshima.jp",
-    "slask.pl",
-    "iizuka.fukuoka.jp",
-    "szczytno.pl",
-    "shell",
-    "r\u{00e1}hkker\u{00e1}vju.no",
-    "engine.aero",
-    "memorial.museum",
-    "amami.kagoshima.jp",
-    "h\u{00f8}ylandet.no",
-    "kainan.tokushima.jp",
-    "sardinia.it",
-    "bahcavuotna.no",
-    "vardo.no",
-    "nic.tj",
-    "lutsk.ua",
-    "kviteseid.no",
-    "leclerc",
-    "\u{516c}\u{76ca}",
-    "maizuru.kyoto.jp",
-    "travelchannel",
-    "adv.br",
-    "inami.wakayama.jp",
-    "imdb",
-    "andriabarlettatrani.it",
-    "cesena-forli.it",
-    "catholic",
-    "gov.bt",
-    "gov.br",
-    "gov.bs",
-    "sannohe.aomori.jp",
-    "li.it",
-    "gov.bz",
-    "gov.by",
-    "kawagoe.mie.jp",
-    "usgarden.museum",
-    "bv.nl",
-    "gov.ba",
-    "gov.bo",
-    "gov.bm",
-    "gov.bh",
-    "cinema.museum",
-    "accountants",
-    "inazawa.aichi.jp",
-    "mitake.gifu.jp",
-    "biz.id",
-    "lamer",
-    "kamchatka.ru",
-    "hakone.kanagawa.jp",
-    "schule",
-    "k12.fl.us",
-    "fujitsu",
-    "toyone.aichi.jp",
-    "vin",
-    "blog.br",
-    "stadt.museum",
-    "sodegaura.chiba.jp",
-    "pro.cy",
-    "amakusa.kumamoto.jp",
-    "kyuragi.saga.jp",
-    "hol.no",
-    "sano.tochigi.jp",
-    "k12.vt.us",
-    "dclk",
-    "frana.no",
-    "takashima.shiga.jp",
-    "oita.oita.jp",
-    "indianmarket.museum",
-    "kaga.ishikawa.jp",
-    "paleo.museum",
-    "ind.gt",
-    "yosemite.museum",
-    "fm.no",
-    "ena.gifu.jp",
-    "qa",
-    "ora.gunma.jp",
-    "ikata.ehime.jp",
-    "kitakata.miyazaki.jp",
-    "verm\u{00f6}gensberater",
-    "daejeon.kr",
-    "org.ua",
-    "yusui.kagoshima.jp",
-    "hoteles",
-    "omsk.ru",
-    "pe.it",
-    "fujiidera.osaka.jp",
-    "alaheadju.no",
-    "opole.pl",
-    "\u{8c37}\u{6b4c}",
-    "saka.hiroshima.jp",
-    "kagamiishi.fukushima.jp",
-    "verisign",
-    "jot",
-    "joy",
-    "amex",
-    "grozny.su",
-    "barum.no",
-    "net.ba",
-    "net.bb",
-    "net.bh",
-    "net.bm",
-    "miyada.nagano.jp",
-    "wales.museum",
-    "net.bs",
-    "asahi.mie.jp",
-    "eniwa.hokkaido.jp",
-    "\u{0645}\u{0648}\u{0642}\u{0639}",
-    "net.bz",
-    "recht.pro",
-    "la-spezia.it",
-    "krodsherad.no",
-    "isesaki.gunma.jp",
-    "\u{0628}\u{06be}\u{0627}\u{0631}\u{062a}",
-    "bbc",
-    "upow.gov.pl",
-    "bbt",
-    "tokushima.tokushima.jp",
-    "parachuting.aero",
-    "rzgw.gov.pl",
-    "sannan.hyogo.jp",
-    "chambagri.fr",
-    "oregontrail.museum",
-    "kunst.museum",
-    "franziskaner.museum",
-    "supply",
-    "penza.su",
-    "station.museum",
-    "\u{049b}\u{0430}\u{0437}",
-    "sanagochi.tokushima.jp",
-    "society.museum",
-    "ketrzyn.pl",
-    "kvitsoy.no",
-    "sakado.saitama.jp",
-    "vlaanderen.museum",
-    "bonn.museum",
-    "med.ee",
-    "kaluga.su",
-    "tarama.okinawa.jp",
-    "gob.bo",
-    "aogaki.hyogo.jp",
-    "promo",
-    "omiya.saitama.jp",
-    "tromso.no",
-    "carboniaiglesias.it",
-    "pa.it",
-    "agency",
-    "rns.tn",
-    "erotica.hu",
-    "fuel.aero",
-    "nakano.tokyo.jp",
-    "modern.museum",
-    "lib.wi.us",
-    "fishing",
-    "kashima.ibaraki.jp",
-    "kirovograd.ua",
-    "hokuto.hokkaido.jp",
-    "minobu.yamanashi.jp",
-    "norfolk.museum",
-    "rankoshi.hokkaido.jp",
-    "sky",
-    "\u{5317}\u{6d77}\u{9053}.jp",
-    "he.cn",
-    "furano.hokkaido.jp",
-    "ski",
-    "isahaya.nagasaki.jp",
-    "\u{9577}\u{91ce}.jp",
-    "kawakami.nara.jp",
-    "\u{0628}\u{0627}\u{0632}\u{0627}\u{0631}",
-    "lahppi.no",
-    "kahoku.ishikawa.jp",
-    "miami",
-    "minato.osaka.jp",
-    "vegarshei.no",
-    "wiih.gov.pl",
-    "trentino-a-adige.it",
-    "church",
-    "fl.us",
-    "acct.pro",
-    "naspers",
-    "k12.ec",
-    "kawajima.saitama.jp",
-    "so.it",
-    "izumo.shimane.jp",
-    "kawaminami.miyazaki.jp",
-    "\u{0627}\u{0644}\u{062c}\u{0632}\u{0627}\u{0626}\u{0631}",
-    "pri.ee",
-    "ra.it",
-    "gen.nz",
-    "hi.cn",
-    "stockholm",
-    "capitalone",
-    "godo.gifu.jp",
-    "kunneppu.hokkaido.jp",
-    "kyotanabe.kyoto.jp",
-    "friuliveneziagiulia.it",
-    "aridagawa.wakayama.jp",
-    "badaddja.no",
-    "gotsu.shimane.jp",
-    "trainer.aero",
-    "usantiques.museum",
-    "kanegasaki.iwate.jp",
-    "travelers",
-    "trento.it",
-    "kwpsp.gov.pl",
-    "jinsekikogen.hiroshima.jp",
-    "texas.museum",
-    "clinique",
-    "al.no",
-    "izena.okinawa.jp",
-    "aarborte.no",
-    "aarp",
-    "rec.nf",
-    "bologna.it",
-    "net.to",
-    "otari.nagano.jp",
-    "naie.hokkaido.jp",
-    "clinton.museum",
-    "gu.us",
-    "co.at",
-    "sakyo.kyoto.jp",
-    "royken.no",
-    "godaddy",
-    "co.ao",
-    "pesaro-urbino.it",
-    "fjell.no",
-    "washingtondc.museum",
-    "co.ag",
-    "co.ae",
-    "s\u{00f8}rum.no",
-    "yugawara.kanagawa.jp",
-    "org.bh",
-    "\u{0627}\u{0644}\u{0627}\u{0631}\u{062f}\u{0646}",
-    "hikari.yamaguchi.jp",
-    "tm.cy",
-    "bo.nordland.no",
-    "store.bb",
-    "theatre",
-    "kakamigahara.gifu.jp",
-    "mutual",
-    "toshima.tokyo.jp",
-    "cc.mo.us",
-    "\u{53f0}\u{7063}",
-    "book",
-    "kl\u{00e6}bu.no",
-    "aejrie.no",
-    "koka.shiga.jp",
-    "nikolaev.ua",
-    "notaires.fr",
-    "scientist.aero",
-    "conf.au",
-    "org.bw",
-    "topology.museum",
-    "cq.cn",
-    "kvanangen.no",
-    "org.bz",
-    "hinode.tokyo.jp",
-    "org.ba",
-    "org.bb",
-    "umaji.kochi.jp",
-    "org.bi",
-    "space",
-    "org.bm",
-    "shintomi.miyazaki.jp",
-    "org.bo",
-    "org.br",
-    "org.bs",
-    "org.bt",
-    "tanohata.iwate.jp",
-    "ofunato.iwate.jp",
-    "gildesk\u{00e5}l.no",
-];
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_dates() {
+        let date = |version| {
+            parse_date(version).map(|date| date.duration_since(UNIX_EPOCH).unwrap().as_secs())
+        };
+        assert_eq!(date("1970-01-01_00-00-00_UTC"), Some(0));
+        assert_eq!(date("2000-03-01"), Some(951_868_800));
+        assert_eq!(date("2023-02-09_23-26-00_UTC"), Some(1_675_900_800));
+        assert_eq!(date("2024-13-01_00-00-00_UTC"), None);
+        assert_eq!(date("unknown"), None);
+        assert!(version().is_some());
+        assert!(published_at().is_some());
+    }
+}
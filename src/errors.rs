@@ -1,3 +1,5 @@
+use crate::TldSource;
+use std::fmt;
 use thiserror::Error;
 
 pub type Result<T> = ::std::result::Result<T, TldExtractError>;
@@ -7,6 +9,27 @@ pub enum TldExtractError {
     #[error("no such host: '{0}'")]
     NoHostError(String),
 
+    #[error("invalid host: '{0}'")]
+    InvalidHostError(String),
+
+    #[error("cannot locate the host of '{0}'")]
+    HostSpanError(String),
+
+    #[error("invalid tld cache: {0}")]
+    InvalidCacheError(String),
+
+    #[error("invalid public suffix list: {0}")]
+    InvalidListError(String),
+
+    #[error("outdated bundled snapshot: {0}")]
+    OldSnapshotError(String),
+
+    #[error("cannot load public suffix data, {}", SourceFailures(.0))]
+    NoSourceError(Vec<SourceFailure>),
+
+    #[error("no local public suffix file is set")]
+    NoLocalFileError,
+
     #[error(transparent)]
     UrlParse(#[from] url::ParseError),
 
@@ -14,9 +37,48 @@ pub enum TldExtractError {
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
 
+    #[cfg(feature = "remote")]
+    #[error("cannot download the list from within an async runtime, use TldOption::build_async")]
+    BlockingInRuntimeError,
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
     #[error(transparent)]
     Json(#[from] serde_json::Error),
 }
+
+/// Why a source of public suffix data could not be used
+#[derive(Debug)]
+pub struct SourceFailure {
+    pub source: TldSource,
+    /// The path or url of the source, if it has one
+    pub location: Option<String>,
+    pub error: TldExtractError,
+}
+
+impl fmt::Display for SourceFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} '{}': {}", self.source, location, self.error),
+            None => write!(f, "{}: {}", self.source, self.error),
+        }
+    }
+}
+
+struct SourceFailures<'a>(&'a [SourceFailure]);
+
+impl fmt::Display for SourceFailures<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "no source was tried");
+        }
+        for (i, failure) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", failure)?;
+        }
+        Ok(())
+    }
+}
@@ -22,11 +22,26 @@
 mod cache;
 #[allow(missing_docs)]
 pub mod errors;
+mod refresh;
+mod shared;
+mod trie;
+mod watch;
 
-pub use errors::{Result, TldExtractError};
+pub use errors::{Result, SourceFailure, TldExtractError};
 use idna::punycode;
-use std::collections::HashSet;
+use log::{info, warn};
+pub use refresh::RefreshHandle;
+#[cfg(feature = "remote")]
+pub use refresh::{RefreshOutcome, RefreshSchedule};
+use serde::{Deserialize, Serialize};
+pub use shared::SharedTldExtractor;
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+use std::time::{Duration, SystemTime};
+use trie::{FlatTrie, Match};
 use url::{Host, Url};
+pub use watch::WatchOutcome;
 
 /// The option for `TldExtractor`.
 ///
@@ -50,14 +65,47 @@ pub struct TldOption {
     /// Should tldextract update local cache file if
     /// the cache is fetched from remote or from snapshot
     update_local: bool,
-    /// When cannot finding valid suffix in PSL, should we naively
-    /// treat the last piece of URL as the suffix and
-    /// the last but one piece as the domain?
-    naive_mode: bool,
+    /// When the local public suffix file cannot be loaded, should we
+    /// fall back to the remote list and the bundled snapshot?
+    local_file_fallback: bool,
+    /// How long the local cache can be used before it must be refreshed
+    cache_max_age: Option<Duration>,
+    /// When refreshing an expired local cache fails, should we keep using it?
+    serve_stale_cache: bool,
+    /// How to write the local cache
+    cache_format: CacheFormat,
+    /// How old the bundled snapshot can be before it is reported
+    snapshot_max_age: Option<Duration>,
+    /// Should an outdated bundled snapshot be refused rather than logged?
+    reject_old_snapshot: bool,
+    /// Rules added to the loaded ones
+    extra_suffixes: Vec<String>,
+    /// Rules removed from the loaded ones
+    exclude_suffixes: Vec<String>,
+    /// The urls of the remote list, tried in order, the default ones if unset
+    #[cfg(feature = "remote")]
+    remote_urls: Option<Vec<String>>,
+    /// How long connecting to a remote url can take
+    #[cfg(feature = "remote")]
+    connect_timeout: Option<Duration>,
+    /// How long reading from a remote url can stall
+    #[cfg(feature = "remote")]
+    read_timeout: Option<Duration>,
+    /// The user agent downloading the remote list
+    #[cfg(feature = "remote")]
+    user_agent: Option<String>,
+    /// The client downloading the remote list
+    #[cfg(feature = "remote")]
+    http_client: Option<reqwest::Client>,
 }
 
 impl TldOption {
     /// Set cache_path
+    ///
+    /// By default the cache lives in `$TLDEXTRACT_CACHE`, or else in
+    /// `$XDG_CACHE_HOME/tldextract` or `~/.cache/tldextract`, in a file named
+    /// after the public suffix list and the private_domains setting. Without
+    /// any of these variables, there is no cache unless a path is set.
     pub fn cache_path(mut self, path: &str) -> Self {
         self.cache_path = Some(path.into());
         self
@@ -81,57 +129,572 @@ impl TldOption {
         self
     }
 
+    /// Set local_file_fallback
+    pub fn local_file_fallback(mut self, b: bool) -> Self {
+        self.local_file_fallback = b;
+        self
+    }
+
+    /// Set cache_max_age
+    ///
+    /// A local cache older than this is refreshed from the other sources.
+    /// By default the local cache never expires.
+    ///
+    /// A cache of the remote list is only downloaded again if the list
+    /// changed, otherwise `update_local` just marks it as fresh.
+    pub fn cache_max_age(mut self, max_age: Duration) -> Self {
+        self.cache_max_age = Some(max_age);
+        self
+    }
+
+    /// Set serve_stale_cache
+    ///
+    /// When the local public suffix file and the remote list cannot refresh
+    /// an expired local cache, use the expired cache rather than the
+    /// bundled snapshot or failing.
+    pub fn serve_stale_cache(mut self, b: bool) -> Self {
+        self.serve_stale_cache = b;
+        self
+    }
+
+    /// Set cache_format
+    pub fn cache_format(mut self, format: CacheFormat) -> Self {
+        self.cache_format = format;
+        self
+    }
+
+    /// Set snapshot_max_age
+    ///
+    /// When falling back to the bundled snapshot, log a warning if it was
+    /// published longer ago than this, see `snapshot_version`.
+    pub fn snapshot_max_age(mut self, max_age: Duration) -> Self {
+        self.snapshot_max_age = Some(max_age);
+        self
+    }
+
+    /// Set reject_old_snapshot
+    ///
+    /// Fail to build rather than use a bundled snapshot older than
+    /// `snapshot_max_age`.
+    pub fn reject_old_snapshot(mut self, b: bool) -> Self {
+        self.reject_old_snapshot = b;
+        self
+    }
+
+    /// Set extra_suffixes
+    ///
+    /// Rules in public suffix list syntax, like "corp.example",
+    /// "*.svc.cluster.local" or "!www.corp.example", added to the rules of
+    /// whichever source is loaded. They count as private rules, see
+    /// `SuffixKind::Private`, whatever `private_domains` says. A rule the
+    /// source already has is left as it is, in its own section.
+    ///
+    /// Punycode labels are decoded, as the rules are matched in unicode. A
+    /// rule that is not a valid domain is dropped with a warning.
+    ///
+    /// They are never written to the local cache but added again on each
+    /// load, so changing them takes effect at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::{TldOption, TldResult};
+    ///
+    /// let ext = TldOption::default()
+    ///     .extra_suffixes(vec!["corp.example", "*.cluster.local"])
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     ext.extract("wiki.corp.example").unwrap(),
+    ///     TldResult::new(None, "wiki", "corp.example")
+    /// );
+    /// assert_eq!(
+    ///     ext.extract("db.prod.svc.cluster.local").unwrap(),
+    ///     TldResult::new("db", "prod", "svc.cluster.local")
+    /// );
+    /// ```
+    pub fn extra_suffixes<I, S>(mut self, suffixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.extra_suffixes = suffix_rules(suffixes);
+        self
+    }
+
+    /// Set exclude_suffixes
+    ///
+    /// Rules in public suffix list syntax removed from the rules of whichever
+    /// source is loaded, before `extra_suffixes` are added. A wildcard or an
+    /// exception rule is only removed if given as such, e.g. "*.ck" or "!www.ck".
+    /// The rules actually removed are reported by `TldExtractor::source_info`.
+    ///
+    /// Like extra suffixes, they are never written to the local cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::{TldOption, TldResult};
+    ///
+    /// let ext = TldOption::default()
+    ///     .private_domains(true)
+    ///     .exclude_suffixes(vec!["blogspot.com", "no.such.rule"])
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     ext.extract("alice.blogspot.com").unwrap(),
+    ///     TldResult::new("alice", "blogspot", "com")
+    /// );
+    /// assert_eq!(ext.source_info().excluded_suffixes, vec!["blogspot.com"]);
+    /// ```
+    pub fn exclude_suffixes<I, S>(mut self, suffixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.exclude_suffixes = suffix_rules(suffixes);
+        self
+    }
+
+    /// Set remote_urls
+    ///
+    /// The urls the remote list is downloaded from, each tried in turn until
+    /// one serves a valid list. By default these are publicsuffix.org and its
+    /// GitHub mirror.
+    ///
+    /// Lists from the default urls must look like the full public suffix
+    /// list, other urls can serve lists of any size. A local cache downloaded
+    /// from other urls than these is not used. No urls at all turn the remote
+    /// source off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::{TldOption, TldSource};
+    ///
+    /// let ext = TldOption::default()
+    ///     .cache_path("/nonexistent/tldextract.cache")
+    ///     .remote_urls(Vec::<String>::new())
+    ///     .build();
+    /// assert_eq!(ext.source_info().source, TldSource::Snapshot);
+    /// ```
+    #[cfg(feature = "remote")]
+    pub fn remote_urls<I, S>(mut self, urls: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.remote_urls = Some(urls.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set connect_timeout
+    ///
+    /// Ignored when an `http_client` is set. By default there is none.
+    #[cfg(feature = "remote")]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set read_timeout
+    ///
+    /// How long a download can go without receiving anything before it fails.
+    /// Ignored when an `http_client` is set. By default there is none.
+    #[cfg(feature = "remote")]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Set user_agent
+    ///
+    /// Ignored when an `http_client` is set. Defaults to "tldextract/" followed
+    /// by the version of this crate.
+    #[cfg(feature = "remote")]
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set http_client
+    ///
+    /// The client used to download the remote list, for instance to go
+    /// through a proxy. Otherwise one is built from `connect_timeout`,
+    /// `read_timeout` and `user_agent`.
+    #[cfg(feature = "remote")]
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
     /// Set naive_mode
-    pub fn naive_mode(mut self, b: bool) -> Self {
-        self.naive_mode = b;
+    ///
+    /// This has no effect anymore: when no rule matches, the implicit "*" rule of
+    /// the public suffix list always makes the last label the suffix.
+    #[deprecated(note = "the implicit \"*\" rule now always applies")]
+    pub fn naive_mode(self, _b: bool) -> Self {
         self
     }
 
     /// Build TldExtractor
+    ///
+    /// # Panics
+    ///
+    /// Panics if no public suffix data can be loaded, see `try_build`.
     pub fn build(self) -> TldExtractor {
         TldExtractor::new(self)
     }
+
+    /// Build TldExtractor, or tell why every source of public suffix data failed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::{TldExtractError, TldOption};
+    ///
+    /// let res = TldOption::default()
+    ///     .cache_path("no/such/tld_cache")
+    ///     .local_public_suffix_file("no/such/public_suffix_list.dat")
+    ///     .try_build();
+    ///
+    /// match res {
+    ///     Err(TldExtractError::NoSourceError(failures)) => assert_eq!(failures.len(), 2),
+    ///     _ => panic!("the local public suffix file should not be skipped"),
+    /// }
+    /// ```
+    pub fn try_build(self) -> Result<TldExtractor> {
+        TldExtractor::try_new(self)
+    }
+
+    /// Build TldExtractor from within an async runtime, or tell why every
+    /// source of public suffix data failed
+    ///
+    /// The remote list is downloaded on the runtime of the caller, whereas
+    /// `build` and `try_build` start a runtime of their own, which they cannot
+    /// do from within another one. Local files are still read blocking.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> tldextract::Result<()> {
+    /// use tldextract::TldOption;
+    ///
+    /// let ext = TldOption::default()
+    ///     .http_client(reqwest::Client::new())
+    ///     .build_async()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "remote")]
+    pub async fn build_async(self) -> Result<TldExtractor> {
+        let loaded = cache::get_tld_cache_async(&self).await?;
+        Ok(TldExtractor::from_loaded(&self, loaded))
+    }
+}
+
+/// Rules as the public suffix list writes them, in lowercase unicode as
+/// labels are matched in that form. Rules that are not domains are dropped.
+fn suffix_rules<I, S>(rules: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    rules
+        .into_iter()
+        .filter_map(|rule| {
+            let rule = rule.as_ref().trim();
+            if rule.is_empty() {
+                return None;
+            }
+            let (mark, domain) = match (rule.strip_prefix('!'), rule.strip_prefix("*.")) {
+                (Some(domain), _) => ("!", domain),
+                (None, Some(domain)) => ("*.", domain),
+                (None, None) => ("", rule),
+            };
+            match idna::domain_to_unicode(domain) {
+                (domain, Ok(())) if !domain.is_empty() => Some(format!("{}{}", mark, domain)),
+                _ => {
+                    warn!("Ignoring the suffix rule {:?}, it is not a domain", rule);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// The version of the public suffix list bundled with this crate, from its
+/// "// VERSION:" line, which starts with its publication date, e.g.
+/// "2023-02-09_23-26-00_UTC".
+///
+/// # Examples
+///
+/// ```
+/// let version = tldextract::snapshot_version().unwrap();
+/// assert!(version.starts_with("20"));
+/// ```
+pub fn snapshot_version() -> Option<&'static str> {
+    cache::snapshot_version()
+}
+
+/// How `TldOption::update_local` writes the local cache
+///
+/// Both formats can be read back, whichever is configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheFormat {
+    /// The prebuilt lookup structure, memory mapped and queried in place
+    /// when loaded, so starting up only costs a checksum and a validation
+    /// pass over the file instead of parsing and inserting every rule.
+    ///
+    /// The file must not be modified in place while an extractor uses it,
+    /// this crate only ever replaces it with a new file.
+    #[default]
+    Binary,
+    /// A readable JSON list of the rules, parsed when loaded
+    Json,
+}
+
+/// Where the public suffix rules of a `TldExtractor` come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TldSource {
+    /// The local cache file, see `TldOption::cache_path`
+    LocalCache,
+    /// A local public suffix file, see `TldOption::local_public_suffix_file`
+    LocalFile,
+    /// The public suffix list downloaded from the internet
+    Remote,
+    /// The snapshot bundled with this crate
+    Snapshot,
+}
+
+impl fmt::Display for TldSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TldSource::LocalCache => "local cache",
+            TldSource::LocalFile => "local public suffix file",
+            TldSource::Remote => "remote public suffix list",
+            TldSource::Snapshot => "bundled snapshot",
+        })
+    }
+}
+
+/// Where and when the rules of a `TldExtractor` were loaded,
+/// see `TldExtractor::source_info`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TldSourceInfo {
+    /// The source the rules were loaded from
+    pub source: TldSource,
+    /// The path or url of the source, `None` for the bundled snapshot
+    pub location: Option<String>,
+    /// The version of the public suffix list, if it tells
+    pub list_version: Option<String>,
+    /// The number of rules in use, after `TldOption::exclude_suffixes` and
+    /// `TldOption::extra_suffixes`
+    pub rule_count: usize,
+    /// The rules added by `TldOption::extra_suffixes`, leaving out those the
+    /// source already had
+    pub extra_suffixes: Vec<String>,
+    /// The rules removed by `TldOption::exclude_suffixes`, leaving out those
+    /// the source did not have
+    pub excluded_suffixes: Vec<String>,
+    /// The rules come from a local cache older than `TldOption::cache_max_age`,
+    /// because refreshing it failed
+    pub stale: bool,
+    /// When the rules were loaded
+    pub loaded_at: SystemTime,
 }
 
 /// The tld extractor, see TldOption for more docs.
 #[derive(Debug)]
 pub struct TldExtractor {
-    tld_cache: HashSet<String>,
-    naive_mode: bool,
+    tld_cache: FlatTrie,
+    source_info: TldSourceInfo,
+    /// The number of rules of the source, before the extra and excluded ones
+    source_rule_count: usize,
 }
 
 impl TldExtractor {
     /// Constructs a new `TldExtractor`.
     ///
     /// see TldOption for more docs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no public suffix data can be loaded, see `try_new`.
     pub fn new(option: TldOption) -> TldExtractor {
-        let cache_path = option.cache_path.as_ref().map(|s| &s[..]);
-        let tld_cache = cache::get_tld_cache(
-            cache_path,
-            option.local_public_suffix_file,
-            option.private_domains,
+        match TldExtractor::try_new(option) {
+            Ok(ext) => ext,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Constructs a new `TldExtractor`, or tell why every source of
+    /// public suffix data failed.
+    ///
+    /// see TldOption for more docs.
+    pub fn try_new(option: TldOption) -> Result<TldExtractor> {
+        let loaded = cache::get_tld_cache(&option)?;
+        Ok(TldExtractor::from_loaded(&option, loaded))
+    }
+
+    fn from_loaded(option: &TldOption, loaded: cache::LoadedRules) -> TldExtractor {
+        let source_rule_count = loaded.rules.trie.rule_count();
+        info!(
+            "Loaded {} TLD rules from {}",
+            source_rule_count, loaded.source
         );
+        // the cache only ever holds the rules of the source
         if option.update_local {
-            let _ = cache::set_tld_cache(cache_path, &tld_cache);
+            let _ = cache::set_tld_cache(option, &loaded);
         }
+        let (rules, extra_suffixes, excluded_suffixes) = loaded
+            .rules
+            .with_overrides(&option.exclude_suffixes, &option.extra_suffixes);
         TldExtractor {
-            tld_cache,
-            naive_mode: option.naive_mode,
+            source_info: TldSourceInfo {
+                rule_count: rules.trie.rule_count(),
+                extra_suffixes,
+                excluded_suffixes,
+                source: loaded.source,
+                location: loaded.location,
+                list_version: rules.version,
+                stale: loaded.stale,
+                loaded_at: SystemTime::now(),
+            },
+            tld_cache: rules.trie,
+            source_rule_count,
         }
     }
 
+    /// Tell which source the rules of this extractor were loaded from, and when.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::{TldOption, TldSource};
+    ///
+    /// let ext = TldOption::default()
+    ///     .cache_path("no/such/tld_cache")
+    ///     .build();
+    /// let info = ext.source_info();
+    ///
+    /// // without a cache, the rules were downloaded or come from the snapshot
+    /// assert_ne!(info.source, TldSource::LocalCache);
+    /// assert!(!info.stale);
+    /// assert!(info.rule_count > 0);
+    /// ```
+    pub fn source_info(&self) -> &TldSourceInfo {
+        &self.source_info
+    }
+
     /// Extract (subdomain, domain, domain suffix) tuple from a given url or bare domain
     pub fn extract(&self, url: &str) -> Result<TldResult> {
-        self._extract(url, None)
+        self._extract(url).map(TldResultRef::into_owned)
     }
 
-    /// Extract (subdomain, domain, domain suffix) tuple from a given url or bare domain
-    /// but override the universal naive_mode in TldExtractor.
+    /// Extract (subdomain, domain, domain suffix) tuple from a given url or bare domain.
+    ///
+    /// This is the same as `extract` now that the implicit "*" rule always applies.
+    #[deprecated(note = "the implicit \"*\" rule now always applies, use `extract`")]
     pub fn extract_naive(&self, url: &str) -> Result<TldResult> {
-        self._extract(url, true)
+        self.extract(url)
+    }
+
+    /// Extract (subdomain, domain, domain suffix) tuple from a given url or bare domain
+    /// without copying.
+    ///
+    /// The parts borrow from `url` unless the host has to be punycode decoded
+    /// or normalized by URL parsing first, see `TldResultRef`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use tldextract::TldOption;
+    ///
+    /// let ext = TldOption::default().build();
+    /// let res = ext.extract_ref("forums.bbc.co.uk").unwrap();
+    ///
+    /// assert_eq!(res.domain, Some(Cow::Borrowed("bbc")));
+    /// assert_eq!(res.suffix, Some(Cow::Borrowed("co.uk")));
+    /// ```
+    pub fn extract_ref<'a>(&self, url: &'a str) -> Result<TldResultRef<'a>> {
+        self._extract(url)
+    }
+
+    /// Locate (subdomain, domain, domain suffix) in a given url or bare domain.
+    ///
+    /// The returned byte ranges point into `url` itself, so the registered
+    /// domain of a url can be replaced in place even when it contains
+    /// userinfo, a port or an internationalized host.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::TldOption;
+    ///
+    /// let ext = TldOption::default().build();
+    /// let url = "https://user@com.example.com:8080/";
+    /// let spans = ext.extract_spans(url).unwrap();
+    ///
+    /// assert_eq!(&url[spans.subdomain.unwrap()], "com");
+    /// assert_eq!(&url[spans.domain.unwrap()], "example");
+    /// assert_eq!(&url[spans.suffix.unwrap()], "com");
+    /// ```
+    pub fn extract_spans(&self, url: &str) -> Result<TldSpans> {
+        if !url.contains(':') {
+            return self.extract_label_spans(url, 0..url.len(), url);
+        }
+
+        let u = Url::parse(url)?;
+
+        let host = u
+            .host()
+            .ok_or_else(|| TldExtractError::NoHostError(url.into()))?;
+        let span = host_span(url).ok_or_else(|| TldExtractError::HostSpanError(url.into()))?;
+
+        match host {
+            Host::Domain(host) => self.extract_label_spans(host, span, url),
+            Host::Ipv4(_) => Ok(TldSpans {
+                domain: Some(span),
+                ..Default::default()
+            }),
+            // leave out the brackets, just like the extracted domain does
+            Host::Ipv6(_) => Ok(TldSpans {
+                domain: Some(span.start + 1..span.end - 1),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Extract (subdomain, domain, domain suffix) tuple from a given url or bare domain,
+    /// along with the public suffix list rule that decided the split.
+    ///
+    /// There is no rule for IP hosts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::{RuleKind, SuffixKind, TldOption, TldResult};
+    ///
+    /// let ext = TldOption::default().build();
+    /// let (res, rule) = ext.extract_explain("http://www.ck").unwrap();
+    /// let rule = rule.unwrap();
+    ///
+    /// assert_eq!(res, TldResult::new(None, "www", "ck"));
+    /// assert_eq!(rule.rule, "!www.ck");
+    /// assert_eq!(rule.kind, RuleKind::Exception);
+    /// assert_eq!(rule.suffix_kind, SuffixKind::Icann);
+    /// ```
+    pub fn extract_explain(&self, url: &str) -> Result<(TldResult, Option<TldRule>)> {
+        let res = self._extract(url)?;
+        let rule = res.rule();
+        Ok((res.into_owned(), rule))
     }
 
-    fn _extract<O: Into<Option<bool>>>(&self, url: &str, naive: O) -> Result<TldResult> {
+    fn _extract<'a>(&self, url: &'a str) -> Result<TldResultRef<'a>> {
         if url.contains(':') {
             // : should only be in a URL, so parse as a URL
 
@@ -142,93 +705,238 @@ impl TldExtractor {
                 .ok_or_else(|| TldExtractError::NoHostError(url.into()))?;
 
             match host {
-                Host::Domain(host) => {
-                    Ok(self.extract_triple(host, naive.into().unwrap_or(self.naive_mode)))
-                }
-                Host::Ipv4(ip) => Ok(TldResult {
-                    domain: Some(ip.to_string()),
+                Host::Domain(host) => match host_span(url).map(|span| &url[span]) {
+                    // the host survived URL parsing untouched, so we can still borrow it
+                    Some(raw) if raw == host => self.extract_triple(raw),
+                    _ => Ok(self.extract_triple(host)?.into_static()),
+                },
+                Host::Ipv4(ip) => Ok(TldResultRef {
+                    domain: Some(ip.to_string().into()),
                     ..Default::default()
                 }),
-                Host::Ipv6(ip) => Ok(TldResult {
-                    domain: Some(ip.to_string()),
+                Host::Ipv6(ip) => Ok(TldResultRef {
+                    domain: Some(ip.to_string().into()),
                     ..Default::default()
                 }),
             }
         } else {
             // no scheme, so assume we've just got a domain/subdomain, skip URL parsing
-            Ok(self.extract_triple(url, naive.into().unwrap_or(self.naive_mode)))
+            self.extract_triple(url)
         }
     }
 
-    fn extract_triple(&self, host: &str, naive_mode: bool) -> TldResult {
-        let segs: Vec<_> = host
+    fn extract_triple<'a>(&self, host: &'a str) -> Result<TldResultRef<'a>> {
+        let host = check_labels(host)?;
+        if host
             .split('.')
-            .filter(|&s| !s.is_empty())
-            .map(|seg| {
-                if seg.starts_with("xn--") {
-                    punycode::decode_to_string(seg.trim_start_matches("xn--")).unwrap_or(seg.into())
-                } else {
-                    seg.into()
-                }
-            })
-            .collect();
-
-        let mut suffix = None;
-        let mut subdomain = None;
-        let mut domain = None;
-        for i in 0..segs.len() {
-            let piece = segs[i..].join(".");
-            let exception_piece = "!".to_string() + &piece;
-            let wildcard_piece = "*.".to_string() + &segs[i + 1..].join(".");
-
-            if self
-                .tld_cache
-                .get(&piece)
-                .or_else(|| self.tld_cache.get(&wildcard_piece))
-                .is_some()
-            {
-                let subdomain_idx = if self.tld_cache.get(&exception_piece).is_some() {
-                    suffix = Some(wildcard_piece[2..].to_string());
-                    domain = Some(segs[i].to_string());
-                    i
-                } else {
-                    suffix = Some(piece);
-                    if i != 0 {
-                        domain = Some(segs[i - 1].to_string());
-                        i - 1
-                    } else {
-                        0
-                    }
-                };
-
-                if subdomain_idx != 0 && !segs[0..subdomain_idx].is_empty() {
-                    subdomain = Some(segs[0..subdomain_idx].join("."));
-                }
+            .any(|seg| seg.starts_with("xn--") || seg.chars().any(char::is_uppercase))
+        {
+            return self.extract_triple_decoded(host);
+        }
 
-                break;
-            }
+        // every label is kept verbatim, so the parts are plain slices of the host
+        let found = self.find_suffix(host.rsplit('.'));
+        let (rest, suffix) = match host.rmatch_indices('.').nth(found.len - 1) {
+            Some((i, _)) => (&host[..i], Some(&host[i + 1..])),
+            None => ("", Some(host)),
+        };
+        let (subdomain, domain) = match rest.rsplit_once('.') {
+            Some((subdomain, domain)) => (Some(subdomain), Some(domain)),
+            None if rest.is_empty() => (None, None),
+            None => (None, Some(rest)),
+        };
+
+        Ok(TldResultRef {
+            domain: domain.map(Cow::Borrowed),
+            subdomain: subdomain.map(Cow::Borrowed),
+            suffix: suffix.map(Cow::Borrowed),
+            suffix_kind: Some(found.section),
+            rule_kind: Some(found.rule),
+        })
+    }
+
+    fn extract_triple_decoded(&self, host: &str) -> Result<TldResultRef<'static>> {
+        let segs = decode_labels(host)?;
+        let (domain_start, suffix_start, found) = self.split_labels(&segs);
+
+        Ok(TldResultRef {
+            suffix: join_labels(&segs[suffix_start..]).map(Cow::Owned),
+            subdomain: join_labels(&segs[..domain_start]).map(Cow::Owned),
+            domain: join_labels(&segs[domain_start..suffix_start]).map(Cow::Owned),
+            suffix_kind: Some(found.section),
+            rule_kind: Some(found.rule),
+        })
+    }
+
+    fn extract_label_spans(&self, host: &str, raw: Range<usize>, url: &str) -> Result<TldSpans> {
+        let host = check_labels(host)?;
+        let segs = decode_labels(host)?;
+        let (domain_start, suffix_start, _) = self.split_labels(&segs);
+
+        // the parser may have mapped IDNA dots, decoded percent escapes or lowercased
+        // the host, so make sure every raw label still lines up with a parsed one
+        let spans = raw_label_spans(url, raw);
+        let parsed = host.split('.').filter(|&s| !s.is_empty());
+        if spans.len() != segs.len()
+            || !spans.iter().zip(parsed).all(|(span, parsed)| {
+                let raw = &url[span.clone()];
+                raw.eq_ignore_ascii_case(parsed) || !raw.is_ascii() || raw.contains('%')
+            })
+        {
+            return Err(TldExtractError::HostSpanError(url.into()));
         }
 
-        if let (None, None, None) = (subdomain.as_ref(), domain.as_ref(), suffix.as_ref()) {
-            let mut iter = segs.into_iter().rev();
-            if naive_mode {
-                suffix = iter.next().map(|s| s.to_string());
-            }
-            domain = iter.next().map(|s| s.to_string());
-            let maybe_subdomain = iter.collect::<Vec<_>>().join(".");
-            subdomain = if maybe_subdomain.is_empty() {
-                None
-            } else {
-                Some(maybe_subdomain)
+        Ok(TldSpans {
+            suffix: join_spans(&spans[suffix_start..]),
+            subdomain: join_spans(&spans[..domain_start]),
+            domain: join_spans(&spans[domain_start..suffix_start]),
+        })
+    }
+
+    /// Split decoded labels into (subdomain, domain, suffix) at the returned indices
+    fn split_labels(&self, segs: &[Cow<str>]) -> (usize, usize, Match) {
+        let found = self.find_suffix(segs.iter().rev().map(AsRef::as_ref));
+        let suffix_start = segs.len().saturating_sub(found.len);
+        (suffix_start.saturating_sub(1), suffix_start, found)
+    }
+
+    /// Find the prevailing rule for a host, falling back to the implicit "*" rule
+    fn find_suffix<'a, I>(&self, labels: I) -> Match
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        self.tld_cache.find(labels).unwrap_or(Match {
+            len: 1,
+            rule: RuleKind::Default,
+            section: SuffixKind::Naive,
+        })
+    }
+}
+
+/// Strip the root label off a host and make sure no other label is empty
+fn check_labels(host: &str) -> Result<&str> {
+    let labels = host.strip_suffix('.').unwrap_or(host);
+    if labels.split('.').any(str::is_empty) {
+        return Err(TldExtractError::InvalidHostError(host.into()));
+    }
+    Ok(labels)
+}
+
+/// Punycode decode and lowercase every label, as rules are matched in that form.
+/// A label that is not valid punycode, or decodes to nothing, makes the host invalid.
+fn decode_labels(host: &str) -> Result<Vec<Cow<'_, str>>> {
+    host.split('.')
+        .map(|seg| {
+            // the ACE prefix is case-insensitive too
+            let seg = lowercase(seg.into());
+            let puny = match seg.strip_prefix("xn--") {
+                Some(puny) => puny,
+                None => return Ok(seg),
+            };
+            match punycode::decode_to_string(puny) {
+                Some(decoded) if !decoded.is_empty() => Ok(lowercase(decoded.into())),
+                _ => Err(TldExtractError::InvalidHostError(host.into())),
             }
-        }
+        })
+        .collect()
+}
 
-        TldResult {
-            suffix,
-            subdomain,
-            domain,
+fn lowercase(label: Cow<'_, str>) -> Cow<'_, str> {
+    if label.chars().any(char::is_uppercase) {
+        label.to_lowercase().into()
+    } else {
+        label
+    }
+}
+
+/// The spans of the non-empty labels of the raw host at `host` in `url`,
+/// splitting on every dot that IDNA maps to a full stop.
+fn raw_label_spans(url: &str, host: Range<usize>) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = host.start;
+    for (i, c) in url[host.clone()].char_indices() {
+        if matches!(c, '.' | '\u{3002}' | '\u{ff0e}' | '\u{ff61}') {
+            spans.push(start..host.start + i);
+            start = host.start + i + c.len_utf8();
         }
     }
+    spans.push(start..host.end);
+    spans.retain(|span| !span.is_empty());
+    spans
+}
+
+fn join_spans(spans: &[Range<usize>]) -> Option<Range<usize>> {
+    match (spans.first(), spans.last()) {
+        (Some(first), Some(last)) => Some(first.start..last.end),
+        _ => None,
+    }
+}
+
+fn join_labels(labels: &[Cow<str>]) -> Option<String> {
+    if labels.is_empty() {
+        None
+    } else {
+        Some(labels.join("."))
+    }
+}
+
+/// Locate the host of a URL in the raw, unparsed input.
+///
+/// This does not validate anything, the caller should compare the
+/// located host against the one `Url::parse` came up with.
+fn host_span(url: &str) -> Option<Range<usize>> {
+    let start = url.len() - url.trim_start_matches(|c| c <= ' ').len();
+    let scheme_end = start + url[start..].find(':')?;
+    let authority_start = url.len() - url[scheme_end + 1..].trim_start_matches(['/', '\\']).len();
+    let authority_end = url[authority_start..]
+        .find(['/', '\\', '?', '#'])
+        .map_or(url.len(), |i| authority_start + i);
+    let host_start = url[authority_start..authority_end]
+        .rfind('@')
+        .map_or(authority_start, |i| authority_start + i + 1);
+    let host = &url[host_start..authority_end];
+    let host_len = if host.starts_with('[') {
+        host.find(']').map_or(host.len(), |i| i + 1)
+    } else {
+        host.find(':').unwrap_or(host.len())
+    };
+    Some(host_start..host_start + host_len)
+}
+
+/// Where the suffix of an extracted host comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuffixKind {
+    /// A rule from the ICANN section of the public suffix list
+    Icann,
+    /// A rule from the private section of the public suffix list, e.g. "github.io"
+    Private,
+    /// No rule matched, the last label is the suffix by the implicit "*" rule
+    Naive,
+}
+
+/// The kind of public suffix list rule that decided an extraction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleKind {
+    /// A plain rule, e.g. "co.uk"
+    Normal,
+    /// A wildcard rule, e.g. "*.ck"
+    Wildcard,
+    /// An exception to a wildcard rule, e.g. "!www.ck"
+    Exception,
+    /// No rule matched, so the implicit "*" rule prevails
+    Default,
+}
+
+/// The public suffix list rule that decided an extraction,
+/// see `TldExtractor::extract_explain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TldRule {
+    /// The rule as written in the list, e.g. "*.ck"
+    pub rule: String,
+    /// The kind of the rule
+    pub kind: RuleKind,
+    /// The section of the list the rule is in
+    pub suffix_kind: SuffixKind,
 }
 
 /// The Tld Result Type
@@ -240,7 +948,7 @@ impl TldExtractor {
 ///
 /// TldResult { domain: Some("google".to_string()), subdomain: Some("www".to_string()), suffix: Some("com".to_string())};
 /// ```
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct TldResult {
     /// The "google" part of "www.google.com"
     pub domain: Option<String>,
@@ -276,4 +984,168 @@ impl TldResult {
             suffix: suffix.into().map(|s| s.into()),
         }
     }
+
+    /// The domain and suffix joined together, e.g. "google.com" for "www.google.com".
+    ///
+    /// This is `None` when either part is missing, which is always the case for
+    /// IP hosts and for hosts that are a suffix themselves. A suffix from the
+    /// implicit "*" rule counts like any other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::TldResult;
+    /// assert_eq!(TldResult::new("forums", "bbc", "co.uk").top_domain_under_public_suffix(),
+    ///   Some("bbc.co.uk".to_string()));
+    /// assert_eq!(TldResult::new(None, "127.0.0.1", None).top_domain_under_public_suffix(), None);
+    /// ```
+    pub fn top_domain_under_public_suffix(&self) -> Option<String> {
+        match (&self.domain, &self.suffix) {
+            (Some(domain), Some(suffix)) => Some(format!("{}.{}", domain, suffix)),
+            _ => None,
+        }
+    }
+
+    /// The registered domain (eTLD+1), an alias of `top_domain_under_public_suffix`.
+    pub fn registered_domain(&self) -> Option<String> {
+        self.top_domain_under_public_suffix()
+    }
+
+    /// The fully qualified domain name, e.g. "www.google.com".
+    ///
+    /// Like `top_domain_under_public_suffix` this is `None` unless both the domain
+    /// and the suffix are present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::TldResult;
+    /// assert_eq!(TldResult::new("forums.news", "cnn", "com").fqdn(),
+    ///   Some("forums.news.cnn.com".to_string()));
+    /// assert_eq!(TldResult::new("internal", "host", None).fqdn(), None);
+    /// ```
+    pub fn fqdn(&self) -> Option<String> {
+        let registered_domain = self.top_domain_under_public_suffix()?;
+        match &self.subdomain {
+            Some(subdomain) => Some(format!("{}.{}", subdomain, registered_domain)),
+            None => Some(registered_domain),
+        }
+    }
+}
+
+/// The borrowed counterpart of `TldResult`, see `TldExtractor::extract_ref`.
+///
+/// Each part borrows from the extracted url when it appears there verbatim,
+/// and only owns its data when the host had to be punycode decoded or
+/// normalized while parsing the url.
+#[derive(Debug, Default, Clone)]
+pub struct TldResultRef<'a> {
+    /// The "google" part of "www.google.com"
+    pub domain: Option<Cow<'a, str>>,
+    /// The "www" part of "www.google.com"
+    pub subdomain: Option<Cow<'a, str>>,
+    /// The "com" part of "www.google.com"
+    pub suffix: Option<Cow<'a, str>>,
+    suffix_kind: Option<SuffixKind>,
+    rule_kind: Option<RuleKind>,
+}
+
+impl TldResultRef<'_> {
+    /// Which section of the public suffix list the suffix was found in.
+    ///
+    /// This is `None` when there is no suffix, and for results converted
+    /// from a `TldResult`. See also `TldExtractor::extract_explain`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tldextract::{SuffixKind, TldOption};
+    ///
+    /// let ext = TldOption::default().build();
+    /// let res = ext.extract_ref("https://m.facebook.com").unwrap();
+    /// assert_eq!(res.suffix_kind(), Some(SuffixKind::Icann));
+    /// ```
+    pub fn suffix_kind(&self) -> Option<SuffixKind> {
+        self.suffix_kind
+    }
+
+    /// Convert into an owned `TldResult`, copying only the borrowed parts
+    pub fn into_owned(self) -> TldResult {
+        TldResult {
+            domain: self.domain.map(Cow::into_owned),
+            subdomain: self.subdomain.map(Cow::into_owned),
+            suffix: self.suffix.map(Cow::into_owned),
+        }
+    }
+
+    /// Stop borrowing, keeping the suffix and rule kinds
+    fn into_static(self) -> TldResultRef<'static> {
+        let owned = |part: Option<Cow<str>>| part.map(|s| Cow::Owned(s.into_owned()));
+        TldResultRef {
+            domain: owned(self.domain),
+            subdomain: owned(self.subdomain),
+            suffix: owned(self.suffix),
+            suffix_kind: self.suffix_kind,
+            rule_kind: self.rule_kind,
+        }
+    }
+
+    /// The rule that split this result
+    fn rule(&self) -> Option<TldRule> {
+        let kind = self.rule_kind?;
+        let suffix = self.suffix.as_deref().unwrap_or("");
+        let rule = match kind {
+            RuleKind::Normal => suffix.to_string(),
+            RuleKind::Wildcard => match suffix.split_once('.') {
+                Some((_, parent)) => format!("*.{}", parent),
+                None => "*".to_string(),
+            },
+            RuleKind::Exception => {
+                let domain = self.domain.as_deref().unwrap_or("");
+                match suffix {
+                    "" => format!("!{}", domain),
+                    _ => format!("!{}.{}", domain, suffix),
+                }
+            }
+            RuleKind::Default => "*".to_string(),
+        };
+        Some(TldRule {
+            rule,
+            kind,
+            suffix_kind: self.suffix_kind?,
+        })
+    }
+}
+
+impl PartialEq for TldResultRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.domain == other.domain
+            && self.subdomain == other.subdomain
+            && self.suffix == other.suffix
+    }
+}
+
+impl Eq for TldResultRef<'_> {}
+
+impl From<TldResult> for TldResultRef<'static> {
+    fn from(res: TldResult) -> Self {
+        TldResultRef {
+            domain: res.domain.map(Cow::Owned),
+            subdomain: res.subdomain.map(Cow::Owned),
+            suffix: res.suffix.map(Cow::Owned),
+            ..Default::default()
+        }
+    }
+}
+
+/// Byte ranges of the parts of a `TldResult` within the extracted url,
+/// see `TldExtractor::extract_spans`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TldSpans {
+    /// Where the "google" part of "www.google.com" is
+    pub domain: Option<Range<usize>>,
+    /// Where the "www" part of "www.google.com" is
+    pub subdomain: Option<Range<usize>>,
+    /// Where the "com" part of "www.google.com" is
+    pub suffix: Option<Range<usize>>,
 }
@@ -0,0 +1,222 @@
+#[cfg(feature = "remote")]
+use log::{debug, warn};
+#[cfg(feature = "remote")]
+use std::collections::hash_map::RandomState;
+#[cfg(feature = "remote")]
+use std::hash::{BuildHasher, Hasher};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+#[cfg(feature = "remote")]
+use crate::{cache, SharedTldExtractor, TldExtractError, TldExtractor, TldOption, TldSourceInfo};
+
+/// When `SharedTldExtractor::start_refresh` downloads the list again
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tldextract::RefreshSchedule;
+///
+/// let schedule = RefreshSchedule::default()
+///     .interval(Duration::from_secs(6 * 3600))
+///     .jitter(Duration::from_secs(600))
+///     .retry_backoff(Duration::from_secs(30));
+/// ```
+#[cfg(feature = "remote")]
+#[derive(Debug, Clone)]
+pub struct RefreshSchedule {
+    /// The time between two refreshes
+    interval: Duration,
+    /// The most time added at random to each wait
+    jitter: Duration,
+    /// The wait after the first failed refresh, doubled on each failure
+    retry_backoff: Duration,
+    /// The longest wait after a failed refresh
+    max_backoff: Duration,
+}
+
+#[cfg(feature = "remote")]
+impl Default for RefreshSchedule {
+    fn default() -> RefreshSchedule {
+        RefreshSchedule {
+            interval: Duration::from_secs(24 * 3600),
+            jitter: Duration::from_secs(3600),
+            retry_backoff: Duration::from_secs(60),
+            max_backoff: Duration::from_secs(24 * 3600),
+        }
+    }
+}
+
+#[cfg(feature = "remote")]
+impl RefreshSchedule {
+    /// Set interval
+    ///
+    /// The time between a refresh and the next one. Defaults to a day.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set jitter
+    ///
+    /// Each wait is lengthened by a random time up to this, so that many
+    /// processes started together do not refresh together. Defaults to an hour.
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set retry_backoff
+    ///
+    /// The wait after a failed refresh, doubled on each failure in a row.
+    /// Defaults to a minute.
+    pub fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Set max_backoff
+    ///
+    /// The longest wait after a failed refresh. Defaults to a day.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// The wait after `failures` failed refreshes in a row, before jitter
+    fn delay(&self, failures: u32) -> Duration {
+        if failures == 0 {
+            return self.interval;
+        }
+        let factor = 1u32.checked_shl(failures - 1).unwrap_or(u32::MAX);
+        self.retry_backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+
+    fn jittered(&self, delay: Duration) -> Duration {
+        let nanos = self.jitter.as_nanos() as u64;
+        if nanos == 0 {
+            return delay;
+        }
+        let random = RandomState::new().build_hasher().finish();
+        delay + Duration::from_nanos(random % (nanos + 1))
+    }
+}
+
+/// What a background refresh did, see `SharedTldExtractor::start_refresh`
+#[cfg(feature = "remote")]
+#[derive(Debug)]
+pub enum RefreshOutcome {
+    /// A new list was downloaded and swapped in
+    Updated(TldSourceInfo),
+    /// The list did not change since it was cached, the rules were reloaded
+    /// and the local cache marked as fresh
+    NotModified(TldSourceInfo),
+    /// The rules were kept as they are, the refresh is retried after a while
+    Failed {
+        /// Why every mirror failed
+        error: TldExtractError,
+        /// The wait before the next attempt, before jitter
+        retry_in: Duration,
+    },
+}
+
+/// The background refresh started by `SharedTldExtractor::start_refresh` or
+/// `SharedTldExtractor::watch_local_file`.
+///
+/// Dropping the handle stops the refresh too, without waiting for it.
+#[derive(Debug)]
+pub struct RefreshHandle {
+    stop: Sender<()>,
+    thread: JoinHandle<()>,
+}
+
+impl RefreshHandle {
+    /// Stop refreshing, waiting for a refresh in progress to end
+    pub fn stop(self) {
+        let _ = self.stop.send(());
+        let _ = self.thread.join();
+    }
+}
+
+/// Run `step` on a thread of its own after `wait`, then again after each
+/// wait it returns, until the handle is stopped or dropped
+pub(crate) fn spawn_loop<F>(wait: Duration, mut step: F) -> RefreshHandle
+where
+    F: FnMut() -> Duration + Send + 'static,
+{
+    let (stop, stopped) = mpsc::channel();
+    let thread = thread::spawn(move || {
+        let mut wait = wait;
+        while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(wait) {
+            wait = step();
+        }
+    });
+    RefreshHandle { stop, thread }
+}
+
+#[cfg(feature = "remote")]
+pub(crate) fn spawn<F>(
+    shared: SharedTldExtractor,
+    option: TldOption,
+    schedule: RefreshSchedule,
+    mut on_refresh: F,
+) -> RefreshHandle
+where
+    F: FnMut(RefreshOutcome) + Send + 'static,
+{
+    let mut failures = 0;
+    let wait = schedule.jittered(schedule.delay(failures));
+    spawn_loop(wait, move || {
+        let outcome = match cache::refresh_tld_cache(&option) {
+            Ok((loaded, modified)) => {
+                failures = 0;
+                let ext = TldExtractor::from_loaded(&option, loaded);
+                let info = ext.source_info().clone();
+                shared.swap(ext);
+                if modified {
+                    RefreshOutcome::Updated(info)
+                } else {
+                    RefreshOutcome::NotModified(info)
+                }
+            }
+            Err(error) => {
+                failures = failures.saturating_add(1);
+                let retry_in = schedule.delay(failures);
+                warn!(
+                    "Refreshing TLD data failed, retrying in {:?}: {}",
+                    retry_in, error
+                );
+                RefreshOutcome::Failed { error, retry_in }
+            }
+        };
+        debug!("Refreshed TLD data: {:?}", outcome);
+        on_refresh(outcome);
+        schedule.jittered(schedule.delay(failures))
+    })
+}
+
+#[cfg(all(test, feature = "remote"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule_delays() {
+        let schedule = RefreshSchedule::default()
+            .interval(Duration::from_secs(3600))
+            .jitter(Duration::from_secs(10))
+            .retry_backoff(Duration::from_secs(5))
+            .max_backoff(Duration::from_secs(60));
+        let delays: Vec<_> = (0..7).map(|n| schedule.delay(n).as_secs()).collect();
+        assert_eq!(delays, [3600, 5, 10, 20, 40, 60, 60]);
+        assert_eq!(schedule.delay(u32::MAX), Duration::from_secs(60));
+
+        for _ in 0..100 {
+            let wait = schedule.jittered(Duration::from_secs(5));
+            assert!(wait >= Duration::from_secs(5) && wait <= Duration::from_secs(15));
+        }
+    }
+}
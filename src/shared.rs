@@ -0,0 +1,199 @@
+use arc_swap::ArcSwap;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "remote")]
+use crate::refresh::{self, RefreshOutcome, RefreshSchedule};
+use crate::watch::{self, WatchOutcome};
+use crate::{
+    RefreshHandle, Result, TldExtractError, TldExtractor, TldOption, TldResult, TldResultRef,
+    TldRule, TldSourceInfo, TldSpans,
+};
+
+/// A `TldExtractor` shared across threads, whose rules can be replaced while
+/// it is in use, e.g. to pick up a new public suffix list without restarting.
+///
+/// Clones share the same rules. Extracting never blocks: each call sees either
+/// the rules from before a swap or the ones from after it, never a mix.
+///
+/// # Examples
+///
+/// ```
+/// use tldextract::{SharedTldExtractor, TldOption, TldResult};
+///
+/// let shared = SharedTldExtractor::new(TldOption::default().build());
+///
+/// let reader = shared.clone();
+/// let worker = std::thread::spawn(move || reader.extract("https://m.facebook.com"));
+///
+/// shared.reload(TldOption::default()).unwrap();
+/// let res = worker.join().unwrap().unwrap();
+/// assert_eq!(res, TldResult::new("m", "facebook", "com"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedTldExtractor {
+    current: Arc<ArcSwap<TldExtractor>>,
+}
+
+impl SharedTldExtractor {
+    /// Share an extractor
+    pub fn new(ext: TldExtractor) -> SharedTldExtractor {
+        SharedTldExtractor {
+            current: Arc::new(ArcSwap::from_pointee(ext)),
+        }
+    }
+
+    /// The current extractor, which stays usable after a swap for as long as
+    /// it is held
+    pub fn load(&self) -> Arc<TldExtractor> {
+        self.current.load_full()
+    }
+
+    /// Replace the extractor of every clone, returning the previous one
+    pub fn swap(&self, ext: TldExtractor) -> Arc<TldExtractor> {
+        self.current.swap(Arc::new(ext))
+    }
+
+    /// Load the rules again and swap them in, returning the previous extractor.
+    ///
+    /// If no source of public suffix data works, the current rules are kept.
+    pub fn reload(&self, option: TldOption) -> Result<Arc<TldExtractor>> {
+        Ok(self.swap(option.try_build()?))
+    }
+
+    /// The same as `reload`, but the remote list is downloaded on the runtime
+    /// of the caller, see `TldOption::build_async`.
+    #[cfg(feature = "remote")]
+    pub async fn reload_async(&self, option: TldOption) -> Result<Arc<TldExtractor>> {
+        Ok(self.swap(option.build_async().await?))
+    }
+
+    /// Keep the rules current by downloading the remote list again on the
+    /// `schedule`, on a thread of its own, until the returned handle is
+    /// stopped or dropped.
+    ///
+    /// Each refresh only downloads the list if it changed since it was cached,
+    /// writes it to the local cache if the option says `update_local`, and
+    /// swaps it in. A failed refresh keeps the current rules and is retried
+    /// with a growing backoff. Every outcome is reported to `on_refresh`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::sync::mpsc;
+    /// use tldextract::{RefreshSchedule, SharedTldExtractor, TldOption};
+    ///
+    /// let shared = SharedTldExtractor::new(TldOption::default().build());
+    /// let (tx, outcomes) = mpsc::channel();
+    /// let refresh = shared.start_refresh(
+    ///     TldOption::default().update_local(true),
+    ///     RefreshSchedule::default(),
+    ///     move |outcome| {
+    ///         let _ = tx.send(outcome);
+    ///     },
+    /// );
+    ///
+    /// println!("{:?}", outcomes.recv().unwrap());
+    /// refresh.stop();
+    /// ```
+    #[cfg(feature = "remote")]
+    pub fn start_refresh<F>(
+        &self,
+        option: TldOption,
+        schedule: RefreshSchedule,
+        on_refresh: F,
+    ) -> RefreshHandle
+    where
+        F: FnMut(RefreshOutcome) + Send + 'static,
+    {
+        refresh::spawn(self.clone(), option, schedule, on_refresh)
+    }
+
+    /// Reload the rules whenever the local public suffix file of the option
+    /// changes, checking it every `interval` on a thread of its own, until the
+    /// returned handle is stopped or dropped.
+    ///
+    /// The file is read on every check, a change of its content is noticed
+    /// even if its modification time and size stay the same.
+    ///
+    /// A file that cannot be parsed, or that has less than half as many rules
+    /// as the current source, leaving out the extra and excluded suffixes, is
+    /// rejected and the current rules are kept. Every reload is reported to
+    /// `on_reload`.
+    ///
+    /// Fails with `NoLocalFileError` if the option has no local public suffix
+    /// file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tldextract::{SharedTldExtractor, TldOption};
+    ///
+    /// let option = || {
+    ///     TldOption::default()
+    ///         .cache_path("no/such/tld_cache")
+    ///         .local_public_suffix_file("data/public_suffix_list.dat")
+    /// };
+    /// let shared = SharedTldExtractor::new(option().build());
+    /// let watch = shared
+    ///     .watch_local_file(option(), Duration::from_secs(10), |outcome| {
+    ///         println!("{:?}", outcome);
+    ///     })
+    ///     .unwrap();
+    ///
+    /// watch.stop();
+    /// ```
+    pub fn watch_local_file<F>(
+        &self,
+        option: TldOption,
+        interval: Duration,
+        on_reload: F,
+    ) -> Result<RefreshHandle>
+    where
+        F: FnMut(WatchOutcome) + Send + 'static,
+    {
+        let path = option
+            .local_public_suffix_file
+            .clone()
+            .ok_or(TldExtractError::NoLocalFileError)?;
+        Ok(watch::spawn(
+            self.clone(),
+            option,
+            path,
+            interval,
+            on_reload,
+        ))
+    }
+
+    /// Where and when the current rules were loaded, see `TldExtractor::source_info`
+    pub fn source_info(&self) -> TldSourceInfo {
+        self.current.load().source_info().clone()
+    }
+
+    /// See `TldExtractor::extract`
+    pub fn extract(&self, url: &str) -> Result<TldResult> {
+        self.current.load().extract(url)
+    }
+
+    /// See `TldExtractor::extract_ref`
+    pub fn extract_ref<'a>(&self, url: &'a str) -> Result<TldResultRef<'a>> {
+        self.current.load().extract_ref(url)
+    }
+
+    /// See `TldExtractor::extract_spans`
+    pub fn extract_spans(&self, url: &str) -> Result<TldSpans> {
+        self.current.load().extract_spans(url)
+    }
+
+    /// See `TldExtractor::extract_explain`
+    pub fn extract_explain(&self, url: &str) -> Result<(TldResult, Option<TldRule>)> {
+        self.current.load().extract_explain(url)
+    }
+}
+
+impl From<TldExtractor> for SharedTldExtractor {
+    fn from(ext: TldExtractor) -> SharedTldExtractor {
+        SharedTldExtractor::new(ext)
+    }
+}
@@ -0,0 +1,424 @@
+mod encode;
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Deref;
+
+use crate::{RuleKind, SuffixKind};
+pub(crate) use encode::parse_rule;
+use encode::{ENTRY_LEN, HEADER_LEN};
+
+/// The builder of the public suffix rule store, see `encode::TrieBuilder`.
+/// Lookups happen on its flat encoding, see `FlatTrie`.
+#[derive(Debug, Default)]
+pub(crate) struct SuffixTrie {
+    builder: encode::TrieBuilder,
+}
+
+/// The rule that decided the suffix of a host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Match {
+    /// The number of labels making up the suffix
+    pub len: usize,
+    pub rule: RuleKind,
+    pub section: SuffixKind,
+}
+
+impl SuffixTrie {
+    pub fn new() -> SuffixTrie {
+        SuffixTrie::default()
+    }
+
+    /// Insert a rule in PSL syntax, along with the section it came from.
+    pub fn insert(&mut self, rule: &str, kind: SuffixKind) {
+        let section = match kind {
+            SuffixKind::Icann => encode::ICANN,
+            SuffixKind::Private => encode::PRIVATE,
+            SuffixKind::Naive => encode::NAIVE,
+        };
+        self.builder.insert(rule, section);
+    }
+
+    /// Encode the trie so it can be queried straight from bytes.
+    pub fn to_flat(&self) -> FlatTrie {
+        let bytes = TrieBytes::Owned(self.builder.encode());
+        FlatTrie::new(bytes, 0).expect("a freshly encoded trie is valid")
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, SuffixKind)> for SuffixTrie {
+    fn from_iter<T: IntoIterator<Item = (S, SuffixKind)>>(iter: T) -> Self {
+        let mut trie = SuffixTrie::new();
+        for (rule, kind) in iter {
+            trie.insert(rule.as_ref(), kind);
+        }
+        trie
+    }
+}
+
+/// Where the bytes of a `FlatTrie` live
+pub(crate) enum TrieBytes {
+    Owned(Vec<u8>),
+    Mapped(memmap2::Mmap),
+    Static(&'static [u8]),
+}
+
+impl Deref for TrieBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            TrieBytes::Owned(bytes) => bytes,
+            TrieBytes::Mapped(map) => map,
+            TrieBytes::Static(bytes) => bytes,
+        }
+    }
+}
+
+/// The public suffix rule store, queried in place from its encoding,
+/// see `encode::TrieBuilder::encode`.
+pub(crate) struct FlatTrie {
+    bytes: TrieBytes,
+    /// Where the trie starts in `bytes`
+    offset: usize,
+    rule_count: usize,
+    node_count: usize,
+    edge_count: usize,
+}
+
+impl FlatTrie {
+    /// Check the trie starting at `offset` is well formed, so lookups never
+    /// read out of bounds. Returns `None` if it is not.
+    ///
+    /// The bundled snapshot was encoded by the build script, its nodes and
+    /// edges are only checked in debug builds.
+    pub fn new(bytes: TrieBytes, offset: usize) -> Option<FlatTrie> {
+        let data = bytes.get(offset..)?;
+        let header = |i: usize| -> Option<usize> { Some(read_u32(data, i * 4)? as usize) };
+        let (rule_count, node_count, edge_count, label_len) =
+            (header(0)?, header(1)?, header(2)?, header(3)?);
+        let len = (node_count.checked_add(edge_count)?)
+            .checked_mul(ENTRY_LEN)?
+            .checked_add(HEADER_LEN)?
+            .checked_add(label_len)?;
+        if data.len() != len || node_count == 0 {
+            return None;
+        }
+
+        let trie = FlatTrie {
+            bytes,
+            offset,
+            rule_count,
+            node_count,
+            edge_count,
+        };
+        if cfg!(debug_assertions) || !matches!(trie.bytes, TrieBytes::Static(_)) {
+            trie.check_entries(label_len)?;
+        }
+        Some(trie)
+    }
+
+    /// Check every edge of every node is within bounds, and leads to a node
+    /// further down. Nodes are numbered breadth first, so an edge back to the
+    /// same or an earlier node can only come from a corrupt or crafted file,
+    /// and would make walking the trie loop forever.
+    fn check_entries(&self, label_len: usize) -> Option<()> {
+        let (node_count, edge_count) = (self.node_count, self.edge_count);
+        for node in 0..node_count {
+            let [first, count, _] = self.entry(node)?;
+            if first.checked_add(count)? > edge_count {
+                return None;
+            }
+            for edge in first..first + count {
+                let [_, _, child] = self.entry(node_count + edge)?;
+                if child <= node {
+                    return None;
+                }
+            }
+        }
+        for edge in 0..edge_count {
+            let [start, len, child] = self.entry(node_count + edge)?;
+            if start.checked_add(len)? > label_len || child >= node_count {
+                return None;
+            }
+        }
+        Some(())
+    }
+
+    /// The encoded trie, as written to the cache
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[self.offset..]
+    }
+
+    pub fn rule_count(&self) -> usize {
+        self.rule_count
+    }
+
+    /// Find the prevailing rule for a host, as described on publicsuffix.org.
+    ///
+    /// `labels` must yield the labels of the host from right to left.
+    /// A matching exception rule always prevails, otherwise the matching rule
+    /// with the most labels does. `None` means only the implicit "*" rule matches.
+    pub fn find<'a, I>(&self, labels: I) -> Option<Match>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut longest = None;
+        let mut exception = None;
+        let mut node = 0;
+        for (depth, label) in labels.into_iter().enumerate() {
+            let [_, wildcard, _] = self.flags(node);
+            if let Some(section) = wildcard {
+                longest = Some(Match {
+                    len: depth + 1,
+                    rule: RuleKind::Wildcard,
+                    section,
+                });
+            }
+
+            node = match self.child(node, label) {
+                Some(child) => child,
+                None => break,
+            };
+            let [rule, _, exception_here] = self.flags(node);
+            if let Some(section) = rule {
+                longest = Some(Match {
+                    len: depth + 1,
+                    rule: RuleKind::Normal,
+                    section,
+                });
+            }
+            if let (Some(section), true) = (exception_here, depth > 0) {
+                // the exception rule minus its leftmost label
+                exception = Some(Match {
+                    len: depth,
+                    rule: RuleKind::Exception,
+                    section,
+                });
+            }
+        }
+        exception.or(longest)
+    }
+
+    /// Every rule in PSL syntax, along with the section it came from
+    pub fn rules(&self) -> Vec<(String, SuffixKind)> {
+        let mut rules = Vec::with_capacity(self.rule_count);
+        let mut stack = vec![(0, Vec::new())];
+        while let Some((node, path)) = stack.pop() {
+            let suffix = path.iter().rev().cloned().collect::<Vec<_>>().join(".");
+            let [rule, wildcard, exception] = self.flags(node);
+            if let Some(kind) = rule {
+                rules.push((suffix.clone(), kind));
+            }
+            if let Some(kind) = wildcard {
+                let dot = if suffix.is_empty() { "" } else { "." };
+                rules.push((format!("*{}{}", dot, suffix), kind));
+            }
+            if let Some(kind) = exception {
+                rules.push((format!("!{}", suffix), kind));
+            }
+
+            let [first, count, _] = self.entry(node).unwrap_or_default();
+            for edge in first..first + count {
+                if let Some([start, len, child]) = self.entry(self.node_count + edge) {
+                    let label = &self.labels()[start..start + len];
+                    let mut path = path.clone();
+                    path.push(String::from_utf8_lossy(label).into_owned());
+                    stack.push((child, path));
+                }
+            }
+        }
+        rules
+    }
+
+    fn child(&self, node: usize, label: &str) -> Option<usize> {
+        let [first, count, _] = self.entry(node)?;
+        let labels = self.labels();
+        let (mut lo, mut hi) = (first, first + count);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let [start, len, child] = self.entry(self.node_count + mid)?;
+            match labels[start..start + len].cmp(label.as_bytes()) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return Some(child),
+            }
+        }
+        None
+    }
+
+    /// The rule, wildcard and exception sections of a node
+    fn flags(&self, node: usize) -> [Option<SuffixKind>; 3] {
+        let flags = self.entry(node).map_or(0, |[_, _, flags]| flags as u32);
+        let shifts = [
+            encode::RULE_SHIFT,
+            encode::WILDCARD_SHIFT,
+            encode::EXCEPTION_SHIFT,
+        ];
+        shifts.map(|shift| match (flags >> shift) & 0b11 {
+            encode::ICANN => Some(SuffixKind::Icann),
+            encode::PRIVATE => Some(SuffixKind::Private),
+            encode::NAIVE => Some(SuffixKind::Naive),
+            _ => None,
+        })
+    }
+
+    /// The `i`th entry of the node and edge tables
+    fn entry(&self, i: usize) -> Option<[usize; 3]> {
+        let data = self.as_bytes();
+        let at = HEADER_LEN + i * ENTRY_LEN;
+        Some([
+            read_u32(data, at)? as usize,
+            read_u32(data, at + 4)? as usize,
+            read_u32(data, at + 8)? as usize,
+        ])
+    }
+
+    fn labels(&self) -> &[u8] {
+        let start = HEADER_LEN + (self.node_count + self.edge_count) * ENTRY_LEN;
+        &self.as_bytes()[start..]
+    }
+}
+
+impl fmt::Debug for FlatTrie {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlatTrie")
+            .field("rule_count", &self.rule_count)
+            .field("node_count", &self.node_count)
+            .field("edge_count", &self.edge_count)
+            .finish()
+    }
+}
+
+fn read_u32(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trie(rules: &[&str]) -> FlatTrie {
+        let trie: SuffixTrie = rules.iter().map(|rule| (rule, SuffixKind::Icann)).collect();
+        trie.to_flat()
+    }
+
+    fn find(trie: &FlatTrie, host: &str) -> Option<usize> {
+        trie.find(host.rsplit('.')).map(|m| m.len)
+    }
+
+    #[test]
+    fn plain_rules() {
+        let trie = trie(&["uk", "co.uk"]);
+        assert_eq!(find(&trie, "bbc.co.uk"), Some(2));
+        assert_eq!(find(&trie, "parliament.uk"), Some(1));
+        assert_eq!(find(&trie, "co.uk"), Some(2));
+        assert_eq!(find(&trie, "example.com"), None);
+    }
+
+    #[test]
+    fn wildcard_and_exception_rules() {
+        let trie = trie(&["jp", "*.kawasaki.jp", "!city.kawasaki.jp"]);
+        assert_eq!(find(&trie, "kawasaki.jp"), Some(1));
+        assert_eq!(find(&trie, "random.kawasaki.jp"), Some(3));
+        assert_eq!(find(&trie, "domain.random.kawasaki.jp"), Some(3));
+        assert_eq!(find(&trie, "city.kawasaki.jp"), Some(2));
+        assert_eq!(find(&trie, "www.city.kawasaki.jp"), Some(2));
+    }
+
+    #[test]
+    fn exception_prevails() {
+        let trie = trie(&["ck", "*.ck", "!www.ck", "*.www.ck"]);
+        assert_eq!(find(&trie, "a.b.www.ck"), Some(1));
+        assert_eq!(find(&trie, "a.b.test.ck"), Some(2));
+        assert_eq!(find(&trie, "ck"), Some(1));
+    }
+
+    #[test]
+    fn sections_and_rule_kinds() {
+        let mut trie: SuffixTrie = ["com", "jp", "*.kawasaki.jp"]
+            .iter()
+            .map(|rule| (rule, SuffixKind::Icann))
+            .collect();
+        trie.insert("blogspot.com", SuffixKind::Private);
+        trie.insert("!city.kawasaki.jp", SuffixKind::Private);
+        let trie = trie.to_flat();
+        assert_eq!(
+            trie.find("example.blogspot.com".rsplit('.')),
+            Some(Match {
+                len: 2,
+                rule: RuleKind::Normal,
+                section: SuffixKind::Private
+            })
+        );
+        assert_eq!(
+            trie.find("random.kawasaki.jp".rsplit('.')),
+            Some(Match {
+                len: 3,
+                rule: RuleKind::Wildcard,
+                section: SuffixKind::Icann
+            })
+        );
+        assert_eq!(
+            trie.find("city.kawasaki.jp".rsplit('.')),
+            Some(Match {
+                len: 2,
+                rule: RuleKind::Exception,
+                section: SuffixKind::Private
+            })
+        );
+    }
+
+    #[test]
+    fn flat_round_trip() {
+        let rules = [
+            "com",
+            "co.uk",
+            "uk",
+            "*.ck",
+            "!www.ck",
+            "*.kawasaki.jp",
+            "jp",
+        ];
+        let trie = trie(&rules);
+        assert_eq!(trie.rule_count(), rules.len());
+
+        let mut found: Vec<_> = trie.rules().into_iter().map(|(rule, _)| rule).collect();
+        found.sort();
+        let mut expected: Vec<_> = rules.iter().map(|rule| rule.to_string()).collect();
+        expected.sort();
+        assert_eq!(found, expected);
+
+        let bytes = TrieBytes::Owned(trie.as_bytes().to_vec());
+        let copy = FlatTrie::new(bytes, 0).unwrap();
+        assert_eq!(find(&copy, "www.ck"), Some(1));
+        assert_eq!(find(&copy, "a.b.ck"), Some(2));
+    }
+
+    #[test]
+    fn flat_rejects_malformed_bytes() {
+        let bytes = trie(&["com", "co.uk"]).as_bytes().to_vec();
+        for len in 0..bytes.len() {
+            let truncated = TrieBytes::Owned(bytes[..len].to_vec());
+            assert!(FlatTrie::new(truncated, 0).is_none());
+        }
+
+        // an edge pointing past the node table
+        let mut broken = bytes.clone();
+        let node_count = read_u32(&bytes, 4).unwrap() as usize;
+        let child = HEADER_LEN + node_count * ENTRY_LEN + 8;
+        broken[child..child + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(FlatTrie::new(TrieBytes::Owned(broken), 0).is_none());
+
+        // an edge of the root to itself, and the edge of "uk" back to the
+        // root, would make walking the trie loop forever
+        let edge_count = read_u32(&bytes, 8).unwrap() as usize;
+        let last_child = child + (edge_count - 1) * ENTRY_LEN;
+        for &at in [child, last_child].iter() {
+            let mut cyclic = bytes.clone();
+            cyclic[at..at + 4].copy_from_slice(&0u32.to_le_bytes());
+            assert!(FlatTrie::new(TrieBytes::Owned(cyclic), 0).is_none());
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! The encoding of `FlatTrie`.
+//!
+//! This module does not depend on the rest of the crate, so the build script
+//! can include it to encode the bundled snapshot.
+
+use std::collections::{HashMap, VecDeque};
+
+/// The rule, node, edge and label byte counts
+pub const HEADER_LEN: usize = 16;
+/// The size of a node or an edge
+pub const ENTRY_LEN: usize = 12;
+
+/// Section codes, stored on two bits in the node flags
+pub const ICANN: u32 = 1;
+pub const PRIVATE: u32 = 2;
+pub const NAIVE: u32 = 3;
+
+/// Where the section of each kind of rule sits in the node flags
+pub const RULE_SHIFT: u32 = 0;
+pub const WILDCARD_SHIFT: u32 = 2;
+pub const EXCEPTION_SHIFT: u32 = 4;
+
+/// The rule on a line of a public suffix list: its first word, provided a
+/// letter, digit or underscore follows its leading dots, wildcards and
+/// exception marks. Comments and blank lines have none.
+pub fn parse_rule(line: &str) -> Option<&str> {
+    let rule = line.split_whitespace().next()?;
+    let first = rule.trim_start_matches(['.', '*', '!']).chars().next()?;
+    if first.is_alphanumeric() || first == '_' {
+        Some(rule)
+    } else {
+        None
+    }
+}
+
+/// Rules stored label by label from right to left, so "*.kawasaki.jp"
+/// becomes the path `jp -> kawasaki` with the wildcard flag set on the
+/// `kawasaki` node.
+#[derive(Debug, Default)]
+pub struct TrieBuilder {
+    root: Node,
+    rule_count: usize,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: HashMap<String, Node>,
+    flags: u32,
+}
+
+impl TrieBuilder {
+    /// Insert a rule in PSL syntax, along with the code of its section.
+    pub fn insert(&mut self, rule: &str, section: u32) {
+        let (rule, exception) = match rule.strip_prefix('!') {
+            Some(rule) => (rule, true),
+            None => (rule, false),
+        };
+        let (rule, wildcard) = match rule.strip_prefix('*') {
+            Some(rule) => (rule.trim_start_matches('.'), true),
+            None => (rule, false),
+        };
+
+        let mut node = &mut self.root;
+        for label in rule.rsplit('.').filter(|s| !s.is_empty()) {
+            node = node.children.entry(label.to_string()).or_default();
+        }
+
+        let shift = if exception {
+            EXCEPTION_SHIFT
+        } else if wildcard {
+            WILDCARD_SHIFT
+        } else {
+            RULE_SHIFT
+        };
+        if (node.flags >> shift) & 0b11 == 0 {
+            self.rule_count += 1;
+        }
+        node.flags = node.flags & !(0b11 << shift) | (section & 0b11) << shift;
+    }
+
+    /// Encode the trie so it can be queried straight from bytes.
+    ///
+    /// All numbers are little endian `u32`s. After the header come the node
+    /// table, the edge table and the labels. A node is (first edge, edge count,
+    /// flags), the root being node 0. An edge is (label offset, label length,
+    /// child node).
+    ///
+    /// Nodes are numbered breadth first, so the children of a node are
+    /// contiguous in the edge table. They are sorted by label, which lets
+    /// lookups binary search them.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut labels = Vec::new();
+
+        let mut queue = VecDeque::new();
+        queue.push_back(&self.root);
+        let mut next_node = 1;
+        while let Some(node) = queue.pop_front() {
+            let mut children: Vec<_> = node.children.iter().collect();
+            children.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+
+            nodes.push([edges.len() as u32, children.len() as u32, node.flags]);
+            for (label, child) in children {
+                edges.push([labels.len() as u32, label.len() as u32, next_node]);
+                labels.extend_from_slice(label.as_bytes());
+                queue.push_back(child);
+                next_node += 1;
+            }
+        }
+
+        let mut bytes =
+            Vec::with_capacity(HEADER_LEN + (nodes.len() + edges.len()) * ENTRY_LEN + labels.len());
+        for n in [self.rule_count, nodes.len(), edges.len(), labels.len()].iter() {
+            bytes.extend_from_slice(&(*n as u32).to_le_bytes());
+        }
+        for entry in nodes.iter().chain(edges.iter()) {
+            for n in entry.iter() {
+                bytes.extend_from_slice(&n.to_le_bytes());
+            }
+        }
+        bytes.extend_from_slice(&labels);
+        bytes
+    }
+}
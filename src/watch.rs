@@ -0,0 +1,83 @@
+use log::{debug, warn};
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use crate::refresh::{self, RefreshHandle};
+use crate::{cache, SharedTldExtractor, TldExtractError, TldExtractor, TldOption, TldSourceInfo};
+
+/// A reload keeping less than this share of the rules is taken for a
+/// truncated or broken file, in percent
+const MIN_KEPT_RULES: usize = 50;
+
+/// What reloading the watched file did, see `SharedTldExtractor::watch_local_file`
+#[derive(Debug)]
+pub enum WatchOutcome {
+    /// The file changed and its rules were swapped in
+    Reloaded(TldSourceInfo),
+    /// The file changed but its rules were refused, the previous ones are kept
+    Rejected(TldExtractError),
+}
+
+/// Tells whether a file changed: its modification time, length and CRC32.
+/// The content is checked too, as a rewrite within the resolution of the
+/// modification time keeps the other two.
+type Fingerprint = Option<(SystemTime, u64, u32)>;
+
+fn fingerprint(path: &str) -> Fingerprint {
+    let meta = fs::metadata(path).ok()?;
+    let content = fs::read(path).ok()?;
+    Some((meta.modified().ok()?, meta.len(), crc32fast::hash(&content)))
+}
+
+pub(crate) fn spawn<F>(
+    shared: SharedTldExtractor,
+    option: TldOption,
+    path: String,
+    interval: Duration,
+    mut on_reload: F,
+) -> RefreshHandle
+where
+    F: FnMut(WatchOutcome) + Send + 'static,
+{
+    let mut seen = fingerprint(&path);
+    refresh::spawn_loop(interval, move || {
+        let current = fingerprint(&path);
+        if current == seen {
+            return interval;
+        }
+        // a rejected file is retried once it changes again
+        seen = current;
+
+        debug!("Local public suffix file {} changed, reloading it", path);
+        let outcome = match reload(&shared, &option) {
+            Ok(info) => WatchOutcome::Reloaded(info),
+            Err(error) => {
+                warn!(
+                    "Keeping the previous TLD data, reloading {} failed: {}",
+                    path, error
+                );
+                WatchOutcome::Rejected(error)
+            }
+        };
+        on_reload(outcome);
+        interval
+    })
+}
+
+fn reload(shared: &SharedTldExtractor, option: &TldOption) -> crate::Result<TldSourceInfo> {
+    let loaded = cache::get_local_file_tld_cache(option)?;
+    // both counts leave out the extra and excluded rules
+    let rule_count = loaded.rules.trie.rule_count();
+    let previous = shared.load().source_rule_count;
+    if rule_count * 100 < previous * MIN_KEPT_RULES {
+        return Err(TldExtractError::InvalidListError(format!(
+            "{} rules, down from {}",
+            rule_count, previous
+        )));
+    }
+
+    let ext = TldExtractor::from_loaded(option, loaded);
+    let info = ext.source_info().clone();
+    shared.swap(ext);
+    Ok(info)
+}
@@ -0,0 +1,82 @@
+//! The official test vectors of the public suffix list, see
+//! https://github.com/publicsuffix/list/blob/master/tests/test_psl.txt
+//!
+//! They are checked against the copy of the list the snapshot is built from,
+//! loaded both as a local file and as the bundled snapshot, so that new rules
+//! upstream cannot break them.
+
+extern crate tldextract;
+
+use std::path::PathBuf;
+use tldextract::{TldExtractor, TldOption, TldSource};
+
+fn test_file(name: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", name].iter().collect();
+    path.display().to_string()
+}
+
+fn vendored_list() -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "data", "public_suffix_list.dat"]
+        .iter()
+        .collect();
+    path.display().to_string()
+}
+
+/// The registrable domain of `domain`, in unicode, or `None` for invalid input
+fn registrable_domain(ext: &TldExtractor, domain: &str) -> Option<String> {
+    ext.extract(domain)
+        .ok()
+        .and_then(|res| res.registered_domain())
+}
+
+#[test]
+fn check_public_suffix() {
+    let ext = TldOption::default()
+        .cache_path(&test_file("psl.tld_cache"))
+        .local_public_suffix_file(&vendored_list())
+        .private_domains(true)
+        .build();
+    check_vectors(&ext);
+}
+
+#[test]
+fn check_public_suffix_snapshot() {
+    let ext = TldOption::default()
+        .cache_path(&test_file("no/such/tld_cache"))
+        .private_domains(true);
+    // the remote list may have rules the vectors do not expect yet
+    #[cfg(feature = "remote")]
+    let ext = ext.remote_urls(Vec::<String>::new());
+    let ext = ext.build();
+    assert_eq!(ext.source_info().source, TldSource::Snapshot);
+    check_vectors(&ext);
+}
+
+fn check_vectors(ext: &TldExtractor) {
+    let vectors = std::fs::read_to_string(test_file("test_psl.txt")).unwrap();
+    let mut checked = 0;
+    for line in vectors.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        let (domain, expected) = (fields.next().unwrap(), fields.next().unwrap());
+        let domain = if domain == "null" { "" } else { domain };
+        let expected = match expected {
+            "null" => None,
+            // the extracted parts are always punycode decoded
+            expected => Some(idna::domain_to_unicode(expected).0),
+        };
+
+        assert_eq!(
+            registrable_domain(ext, domain),
+            expected,
+            "checkPublicSuffix('{}')",
+            domain
+        );
+        checked += 1;
+    }
+    assert_eq!(checked, 78);
+}